@@ -1,5 +1,10 @@
 #![allow(clippy::wildcard_imports)]
+use std::cmp::Ordering;
+use std::hint::black_box;
+
 use criterion::{Criterion, criterion_group, criterion_main};
+use dec64::Dec64;
+use dec64::consts::*;
 
 #[path = "../tests/add.rs"]
 mod add;
@@ -23,5 +28,104 @@ fn div(c: &mut Criterion) {
     group.bench_function("all_c", |b| b.iter(div::all_c_tests));
 }
 
-criterion_group!(benches, add, mul, div);
+/// Comparison by subtracting, which is what `PartialOrd` used to do.
+fn cmp_by_subtraction(a: Dec64, b: Dec64) -> Ordering {
+    let diff = a - b;
+    if diff.is_zero() {
+        Ordering::Equal
+    } else if diff.coefficient() > 0 {
+        Ordering::Greater
+    } else {
+        Ordering::Less
+    }
+}
+
+fn cmp(c: &mut Criterion) {
+    let values = [
+        ZERO,
+        ONE,
+        NEGATIVE_ONE,
+        PI,
+        NEGATIVE_PI,
+        HALF,
+        CENT,
+        MAXINT,
+        MININT,
+        Dec64::from_parts(1999, -2),
+        Dec64::from_parts(2001, -2),
+        Dec64::from_parts(20, 0),
+        Dec64::from_parts(12345678, -30),
+        Dec64::from_parts(12345678, 30),
+        TINIEST,
+        MAX,
+        MIN,
+    ];
+    let mut group = c.benchmark_group("cmp");
+    group.bench_function("exact", |b| {
+        b.iter(|| {
+            for &x in &values {
+                for &y in &values {
+                    black_box(black_box(x).cmp(&black_box(y)));
+                }
+            }
+        })
+    });
+    group.bench_function("subtraction", |b| {
+        b.iter(|| {
+            for &x in &values {
+                for &y in &values {
+                    black_box(cmp_by_subtraction(black_box(x), black_box(y)));
+                }
+            }
+        })
+    });
+}
+
+fn from_f64(c: &mut Criterion) {
+    // Uniform floats in [0, 1000) from a fixed xorshift sequence.
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    let uniform: Vec<f64> = (0..1000)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            (state >> 11) as f64 / (1u64 << 53) as f64 * 1000.0
+        })
+        .collect();
+    // Their shortest decimals have 17 digits and don’t fit into the coefficient.
+    let long: Vec<f64> = uniform
+        .iter()
+        .copied()
+        .filter(|float| format!("{float:e}").split('e').next().unwrap().len() > 17)
+        .collect();
+    let short = [
+        0.1,
+        0.25,
+        2.75,
+        1e-10,
+        12345.678,
+        1e100,
+        -2.5,
+        9007199254740991.0,
+    ];
+    let out_of_range = [1e300, 1e-300, -1e200, 5e-200, f64::MAX, f64::MIN_POSITIVE];
+
+    let mut group = c.benchmark_group("from_f64");
+    for (name, values) in [
+        ("short", &short[..]),
+        ("uniform", &uniform[..]),
+        ("long", &long[..]),
+        ("out_of_range", &out_of_range[..]),
+    ] {
+        group.bench_function(name, |b| {
+            b.iter(|| {
+                for &float in values {
+                    black_box(Dec64::from(black_box(float)));
+                }
+            })
+        });
+    }
+}
+
+criterion_group!(benches, add, mul, div, cmp, from_f64);
 criterion_main!(benches);
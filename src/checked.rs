@@ -0,0 +1,89 @@
+//! Checked arithmetic on DEC64.
+//!
+//! The operators in [`core::ops`] map overflow to NaN and underflow to zero,
+//! which cannot be told apart from a NaN operand or an exact zero result.
+//! The checked variants instead return [`None`] in all of these cases.
+
+use crate::{Dec64, ZERO};
+
+impl Dec64 {
+    /// Checked addition. Returns [`None`] if either operand is NaN or the sum overflows.
+    #[inline]
+    pub fn checked_add(self, other: Dec64) -> Option<Dec64> {
+        if self.is_nan() || other.is_nan() {
+            return None;
+        }
+        // The sum of two numbers can only become NaN by overflowing.
+        // It cannot underflow, since the result is at least as precise as the less precise operand.
+        Some(self + other).filter(|sum| !sum.is_nan())
+    }
+
+    /// Checked subtraction. Returns [`None`] if either operand is NaN or the difference overflows.
+    #[inline]
+    pub fn checked_sub(self, other: Dec64) -> Option<Dec64> {
+        if self.is_nan() || other.is_nan() {
+            return None;
+        }
+        Some(self - other).filter(|difference| !difference.is_nan())
+    }
+
+    /// Checked multiplication. Returns [`None`] if either operand is NaN,
+    /// or if the product overflows or underflows to zero.
+    #[inline]
+    pub fn checked_mul(self, other: Dec64) -> Option<Dec64> {
+        if self.is_nan() || other.is_nan() {
+            return None;
+        }
+        if self.is_zero() || other.is_zero() {
+            return Some(ZERO);
+        }
+        // The product of nonzero numbers is nonzero, so a zero result must have underflowed.
+        Some(self * other).filter(|product| !product.is_nan() && !product.is_zero())
+    }
+
+    /// Checked division. Returns [`None`] if either operand is NaN, if the divisor is zero,
+    /// or if the quotient overflows or underflows to zero.
+    #[inline]
+    pub fn checked_div(self, other: Dec64) -> Option<Dec64> {
+        if self.is_nan() || other.is_nan() || other.is_zero() {
+            return None;
+        }
+        if self.is_zero() {
+            return Some(ZERO);
+        }
+        Some(self / other).filter(|quotient| !quotient.is_nan() && !quotient.is_zero())
+    }
+
+    /// Checked remainder. Returns [`None`] if either operand is NaN or the divisor is zero.
+    ///
+    /// The remainder itself is always exact, see [`Dec64::div_rem`].
+    #[inline]
+    pub fn checked_rem(self, other: Dec64) -> Option<Dec64> {
+        if self.is_nan() || other.is_nan() || other.is_zero() {
+            return None;
+        }
+        Some(self % other)
+    }
+
+    /// Checked negation. Returns [`None`] if the operand is NaN or the negation overflows.
+    ///
+    /// The only overflowing negation is that of [`MIN`](crate::consts::MIN).
+    #[inline]
+    pub fn checked_neg(self) -> Option<Dec64> {
+        if self.is_nan() {
+            return None;
+        }
+        Some(-self).filter(|negation| !negation.is_nan())
+    }
+
+    /// Checked absolute value. Returns [`None`] if the operand is NaN or the absolute value overflows.
+    ///
+    /// The only overflowing absolute value is that of [`MIN`](crate::consts::MIN).
+    #[inline]
+    pub fn checked_abs(self) -> Option<Dec64> {
+        if self.is_nan() {
+            return None;
+        }
+        Some(self.abs()).filter(|abs| !abs.is_nan())
+    }
+}
@@ -1,6 +1,7 @@
-use crate::Dec64;
+use crate::{Dec64, POWERS_OF_10};
 
 use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
 
 impl PartialEq<Dec64> for Dec64 {
     /// Compare two DEC64 numbers.
@@ -11,30 +12,108 @@ impl PartialEq<Dec64> for Dec64 {
             return true;
         }
 
-        // Zeroes are equal.
-        if self.is_zero() && other.is_zero() {
-            return true;
+        // Non-trivially equal NaNs have different coefficients.
+        if self.is_nan() || other.is_nan() {
+            return false;
         }
 
-        // Do it the hard way by subtracting. Is the difference zero?
-        (*self - *other).is_zero()
+        self.cmp_numbers(*other) == Ordering::Equal
     }
 }
 
 impl PartialOrd<Dec64> for Dec64 {
+    #[inline]
     fn partial_cmp(&self, other: &Dec64) -> Option<Ordering> {
-        // Trivial and NAN equality.
-        if self.0 == other.0 || (self.is_nan() && other.is_nan()) {
-            Some(Ordering::Equal)
+        Some(self.cmp(other))
+    }
+}
+
+/// DEC64 numbers have a total order, which is consistent with [`PartialEq`]:
+///
+/// - Numbers are ordered by their value, regardless of representation.
+///   For example, `1` and `10e-1` are equal, and all zeros are equal.
+/// - NaNs are greater than all numbers.
+/// - NaNs are ordered among themselves by their coefficient,
+///   so only NaNs with the same coefficient are equal.
+impl Ord for Dec64 {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.is_nan(), other.is_nan()) {
+            (true, true) => self.coefficient().cmp(&other.coefficient()),
+            (true, false) => Ordering::Greater,
+            (false, true) => Ordering::Less,
+            (false, false) => self.cmp_numbers(*other),
+        }
+    }
+}
+
+impl Dec64 {
+    /// Exactly compares two DEC64 numbers that are not NaN.
+    #[inline]
+    fn cmp_numbers(self, other: Dec64) -> Ordering {
+        let self_coefficient = self.coefficient();
+        let other_coefficient = other.coefficient();
+
+        // With equal exponents, the coefficients directly determine the order.
+        // Zeros and numbers of different sign are ordered by the sign of their coefficients alone.
+        if self.exponent() == other.exponent()
+            || self_coefficient == 0
+            || other_coefficient == 0
+            || (self_coefficient < 0) != (other_coefficient < 0)
+        {
+            return self_coefficient.cmp(&other_coefficient);
+        }
+
+        // Both numbers have the same sign. Scale the number with the larger exponent
+        // so that the exponents match.
+        let (hi, hi_coefficient, lo_coefficient, ordering) = if self.exponent() > other.exponent() {
+            (self, self_coefficient, other_coefficient, Ordering::Greater)
         } else {
-            let diff = *self - *other;
-            if diff.is_zero() {
-                Some(Ordering::Equal)
-            } else if diff.coefficient() > 0 {
-                Some(Ordering::Greater)
+            (other, other_coefficient, self_coefficient, Ordering::Less)
+        };
+        let exponent_difference =
+            (hi.exponent() as i16 - self.exponent().min(other.exponent()) as i16) as usize;
+        if exponent_difference >= 17 {
+            // Scaling by 10^17 puts the larger exponent’s coefficient out of reach of any coefficient,
+            // so its magnitude is larger.
+            return if hi_coefficient > 0 {
+                ordering
             } else {
-                Some(Ordering::Less)
+                ordering.reverse()
+            };
+        }
+
+        // |coefficient| < 2^55 and 10^16 < 2^54, so this fits easily into 128 bits.
+        let scaled = hi_coefficient as i128 * POWERS_OF_10[exponent_difference] as i128;
+        let hi_ordering = scaled.cmp(&(lo_coefficient as i128));
+        if ordering == Ordering::Greater {
+            hi_ordering
+        } else {
+            hi_ordering.reverse()
+        }
+    }
+}
+
+/// Hashes DEC64 numbers consistently with [`PartialEq`].
+///
+/// Numbers are hashed in a normalized form without trailing zeros in the coefficient,
+/// so that equal numbers with different representations hash equally.
+impl Hash for Dec64 {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        if self.is_nan() {
+            state.write_u8(2);
+            self.coefficient().hash(state);
+        } else if self.is_zero() {
+            state.write_u8(0);
+        } else {
+            let mut coefficient = self.coefficient();
+            let mut exponent = self.exponent() as i16;
+            while coefficient % 10 == 0 {
+                coefficient /= 10;
+                exponent += 1;
             }
+            state.write_u8(1);
+            coefficient.hash(state);
+            exponent.hash(state);
         }
     }
 }
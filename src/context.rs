@@ -0,0 +1,246 @@
+//! Arithmetic contexts with status flags and traps, modelled after IEEE 754.
+
+use core::fmt::{self, Debug, Display, Formatter};
+use core::ops::{BitAnd, BitOr, BitOrAssign, Not};
+
+use crate::{Dec64, RoundingMode, Wide};
+
+/// A set of exceptional conditions that arithmetic can signal.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Flags(u8);
+
+impl Flags {
+    /// The result was rounded, i.e. nonzero digits were discarded.
+    pub const INEXACT: Flags = Flags(1 << 0);
+    /// The result was too large in magnitude and became NaN.
+    pub const OVERFLOW: Flags = Flags(1 << 1);
+    /// The result was too small in magnitude to be represented exactly, and became zero or lost digits.
+    pub const UNDERFLOW: Flags = Flags(1 << 2);
+    /// A nonzero number was divided by zero.
+    pub const DIVISION_BY_ZERO: Flags = Flags(1 << 3);
+    /// The operation has no meaningful result, like dividing zero by zero.
+    pub const INVALID: Flags = Flags(1 << 4);
+
+    const NAMES: [(Flags, &'static str); 5] = [
+        (Self::INEXACT, "inexact"),
+        (Self::OVERFLOW, "overflow"),
+        (Self::UNDERFLOW, "underflow"),
+        (Self::DIVISION_BY_ZERO, "division by zero"),
+        (Self::INVALID, "invalid"),
+    ];
+
+    /// No flags.
+    #[inline]
+    pub const fn empty() -> Self {
+        Self(0)
+    }
+
+    /// All flags.
+    #[inline]
+    pub const fn all() -> Self {
+        Self(0b11111)
+    }
+
+    /// Returns `true` if no flag is set.
+    #[inline]
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns `true` if all flags in `other` are set.
+    #[inline]
+    pub const fn contains(self, other: Flags) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns `true` if any flag in `other` is set.
+    #[inline]
+    pub const fn intersects(self, other: Flags) -> bool {
+        self.0 & other.0 != 0
+    }
+}
+
+impl BitOr for Flags {
+    type Output = Flags;
+
+    #[inline]
+    fn bitor(self, rhs: Flags) -> Flags {
+        Flags(self.0 | rhs.0)
+    }
+}
+
+impl BitOrAssign for Flags {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: Flags) {
+        self.0 |= rhs.0;
+    }
+}
+
+impl BitAnd for Flags {
+    type Output = Flags;
+
+    #[inline]
+    fn bitand(self, rhs: Flags) -> Flags {
+        Flags(self.0 & rhs.0)
+    }
+}
+
+impl Not for Flags {
+    type Output = Flags;
+
+    #[inline]
+    fn not(self) -> Flags {
+        Flags(!self.0 & Self::all().0)
+    }
+}
+
+impl Debug for Flags {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_set()
+            .entries(
+                Self::NAMES
+                    .iter()
+                    .filter(|(flag, _)| self.contains(*flag))
+                    .map(|(_, name)| name),
+            )
+            .finish()
+    }
+}
+
+impl Display for Flags {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut names = Self::NAMES
+            .iter()
+            .filter(|(flag, _)| self.contains(*flag))
+            .map(|(_, name)| name);
+        match names.next() {
+            Some(first) => f.write_str(first)?,
+            None => return f.write_str("none"),
+        }
+        for name in names {
+            write!(f, ", {name}")?;
+        }
+        Ok(())
+    }
+}
+
+/// The error returned by a [`Context`] operation that signalled a trapped condition.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Trapped {
+    /// The trapped conditions that were signalled.
+    pub flags: Flags,
+    /// The result that the operation would have returned without traps.
+    pub result: Dec64,
+}
+
+impl Display for Trapped {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "DEC64 operation signalled {}", self.flags)
+    }
+}
+
+impl core::error::Error for Trapped {}
+
+/// An arithmetic context, consisting of a rounding mode, sticky status flags and traps.
+///
+/// Operations executed through a context round with its rounding mode,
+/// and raise the flags for all conditions they signal.
+/// Flags stay raised until they are cleared, so that many operations can be checked at once:
+///
+/// ```
+/// # use dec64::{Context, Flags, consts::*};
+/// let mut context = Context::default();
+/// let third = context.div(ONE, THREE).unwrap();
+/// context.add(third, ONE).unwrap();
+/// assert!(context.flags().contains(Flags::INEXACT));
+/// ```
+///
+/// Conditions that are set in [`Context::traps`] make the operation return [`Trapped`] instead.
+/// Their flags are raised nonetheless.
+///
+/// NaN operands propagate without signalling anything, as the condition that created the NaN has already been signalled.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Context {
+    /// The rounding mode for all operations.
+    pub rounding: RoundingMode,
+    /// The conditions that make operations fail.
+    pub traps: Flags,
+    flags: Flags,
+}
+
+impl Context {
+    /// Creates a context with the given rounding mode, no traps and no flags raised.
+    #[inline]
+    pub const fn new(rounding: RoundingMode) -> Self {
+        Self {
+            rounding,
+            traps: Flags::empty(),
+            flags: Flags::empty(),
+        }
+    }
+
+    /// Returns the flags raised since the context was created or the flags were last cleared.
+    #[inline]
+    pub const fn flags(&self) -> Flags {
+        self.flags
+    }
+
+    /// Lowers all flags.
+    #[inline]
+    pub fn clear_flags(&mut self) {
+        self.flags = Flags::empty();
+    }
+
+    /// Constructs a Dec64 from coefficient and exponent values like [`Dec64::new_rounded`].
+    #[inline]
+    pub fn new_dec64(&mut self, coefficient: i64, exponent: i32) -> Result<Dec64, Trapped> {
+        self.signal(Wide::exact(coefficient.into(), exponent).round(self.rounding))
+    }
+
+    /// Addition like [`Dec64::add_rounded`].
+    #[inline]
+    pub fn add(&mut self, lhs: Dec64, rhs: Dec64) -> Result<Dec64, Trapped> {
+        self.signal(lhs.add_flagged(rhs, self.rounding))
+    }
+
+    /// Subtraction like [`Dec64::sub_rounded`].
+    #[inline]
+    pub fn sub(&mut self, lhs: Dec64, rhs: Dec64) -> Result<Dec64, Trapped> {
+        self.signal(lhs.sub_flagged(rhs, self.rounding))
+    }
+
+    /// Multiplication like [`Dec64::mul_rounded`].
+    #[inline]
+    pub fn mul(&mut self, lhs: Dec64, rhs: Dec64) -> Result<Dec64, Trapped> {
+        self.signal(lhs.mul_flagged(rhs, self.rounding))
+    }
+
+    /// Division like [`Dec64::div_rounded`].
+    ///
+    /// Dividing a nonzero number by zero signals [`Flags::DIVISION_BY_ZERO`],
+    /// and dividing zero by zero signals [`Flags::INVALID`].
+    #[inline]
+    pub fn div(&mut self, lhs: Dec64, rhs: Dec64) -> Result<Dec64, Trapped> {
+        self.signal(lhs.div_flagged(rhs, self.rounding))
+    }
+
+    /// Rounding to decimal places like [`Dec64::round_to_places_with`].
+    #[inline]
+    pub fn round_to_places(&mut self, value: Dec64, places: i8) -> Result<Dec64, Trapped> {
+        self.signal(value.round_to_places_flagged(places, self.rounding))
+    }
+
+    /// Raises the signalled flags, and fails if any of them are trapped.
+    fn signal(&mut self, (result, flags): (Dec64, Flags)) -> Result<Dec64, Trapped> {
+        self.flags |= flags;
+        let trapped = flags & self.traps;
+        if trapped.is_empty() {
+            Ok(result)
+        } else {
+            Err(Trapped {
+                flags: trapped,
+                result,
+            })
+        }
+    }
+}
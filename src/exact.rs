@@ -0,0 +1,48 @@
+//! Exact arithmetic on DEC64.
+//!
+//! These operations only succeed if no rounding happened,
+//! i.e. if no nonzero digit had to be discarded to fit the result into a DEC64.
+
+use crate::{Dec64, Flags, RoundingMode, Wide};
+
+impl Dec64 {
+    /// Construct a Dec64 from coefficient and exponent values, but only if it represents them exactly.
+    ///
+    /// Returns [`None`] if the number is too large or if any nonzero digit would have to be discarded,
+    /// where [`Dec64::new`] would round instead.
+    #[inline]
+    pub fn new_exact(coefficient: i64, exponent: i32) -> Option<Dec64> {
+        Self::exact_result(Wide::exact(coefficient.into(), exponent).round(RoundingMode::HalfUp))
+    }
+
+    /// Exact addition. Returns [`None`] if either operand is NaN or the sum is not exactly representable.
+    #[inline]
+    pub fn add_exact(self, other: Dec64) -> Option<Dec64> {
+        Self::exact_result(self.add_flagged(other, RoundingMode::HalfUp))
+    }
+
+    /// Exact subtraction. Returns [`None`] if either operand is NaN or the difference is not exactly representable.
+    #[inline]
+    pub fn sub_exact(self, other: Dec64) -> Option<Dec64> {
+        Self::exact_result(self.sub_flagged(other, RoundingMode::HalfUp))
+    }
+
+    /// Exact multiplication. Returns [`None`] if either operand is NaN or the product is not exactly representable.
+    #[inline]
+    pub fn mul_exact(self, other: Dec64) -> Option<Dec64> {
+        Self::exact_result(self.mul_flagged(other, RoundingMode::HalfUp))
+    }
+
+    /// Exact division. Returns [`None`] if either operand is NaN, if the divisor is zero,
+    /// or if the quotient is not exactly representable (like `1 / 3`).
+    #[inline]
+    pub fn div_exact(self, other: Dec64) -> Option<Dec64> {
+        Self::exact_result(self.div_flagged(other, RoundingMode::HalfUp))
+    }
+
+    /// Returns the result only if it is a number that no condition was signalled for.
+    #[inline]
+    fn exact_result((result, flags): (Dec64, Flags)) -> Option<Dec64> {
+        Some(result).filter(|result| flags.is_empty() && !result.is_nan())
+    }
+}
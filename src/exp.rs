@@ -0,0 +1,183 @@
+//! Exponentials and logarithms of DEC64.
+//!
+//! All functions are computed natively with 38-digit intermediate results and rounded once, half away from zero,
+//! so they give the same results on every platform. The results are always within one unit in the last place,
+//! and correctly rounded unless the exact result is extremely close to halfway between two DEC64 numbers.
+
+use crate::extended::Extended;
+use crate::{Dec64, NAN, NEGATIVE_ONE, ONE, TEN, TWO, ZERO};
+
+/// Strips trailing zeros from the coefficient of a positive number.
+fn stripped(value: Dec64) -> (u64, i32) {
+    let mut coefficient = value.coefficient() as u64;
+    let mut exponent = value.exponent() as i32;
+    while coefficient.is_multiple_of(10) {
+        coefficient /= 10;
+        exponent += 1;
+    }
+    (coefficient, exponent)
+}
+
+/// Returns `k` if the positive number is exactly `10^k`.
+fn power_of_ten(value: Dec64) -> Option<i32> {
+    let (coefficient, exponent) = stripped(value);
+    (coefficient == 1).then_some(exponent)
+}
+
+/// Returns `k` if the positive number is exactly `2^k`.
+fn power_of_two(value: Dec64) -> Option<i32> {
+    // c × 10^e = 2^k requires e ≤ 0, and c = 2^(k - e) × 5^-e.
+    let (coefficient, exponent) = stripped(value);
+    if exponent > 0 {
+        return None;
+    }
+    let fives = 5u64.checked_pow(exponent.unsigned_abs())?;
+    if !coefficient.is_multiple_of(fives) {
+        return None;
+    }
+    let twos = coefficient / fives;
+    twos.is_power_of_two()
+        .then(|| twos.trailing_zeros() as i32 + exponent)
+}
+
+impl Dec64 {
+    /// Returns `e^self`.
+    ///
+    /// Returns NaN for NaN and if the result is too large, and zero if it is too small.
+    ///
+    /// ```
+    /// # use dec64::{Dec64, consts::*};
+    /// assert_eq!(ONE.exp(), E);
+    /// assert_eq!(ZERO.exp(), ONE);
+    /// ```
+    pub fn exp(self) -> Dec64 {
+        if self.is_nan() {
+            return NAN;
+        }
+        Extended::from_dec64(self).exp().round()
+    }
+
+    /// Returns `e^self - 1`, which is accurate even for numbers close to zero.
+    ///
+    /// Returns NaN for NaN and if the result is too large.
+    ///
+    /// ```
+    /// # use dec64::{Dec64, consts::*};
+    /// let tiny = Dec64::from_parts(1, -20);
+    /// assert_eq!(tiny.exp_m1(), tiny);
+    /// assert_eq!(tiny.exp() - ONE, ZERO);
+    /// ```
+    pub fn exp_m1(self) -> Dec64 {
+        if self.is_nan() {
+            return NAN;
+        }
+        Extended::from_dec64(self).exp_m1().round()
+    }
+
+    /// Returns the natural logarithm.
+    ///
+    /// Returns NaN for NaN, zero and negative numbers.
+    ///
+    /// ```
+    /// # use dec64::{Dec64, consts::*};
+    /// assert_eq!(E.ln(), ONE);
+    /// assert_eq!(TWO.ln(), LN_2);
+    /// ```
+    pub fn ln(self) -> Dec64 {
+        if self.is_nan() || self.coefficient() <= 0 {
+            return NAN;
+        }
+        Extended::from_dec64(self).ln().round()
+    }
+
+    /// Returns `ln(1 + self)`, which is accurate even for numbers close to zero.
+    ///
+    /// Returns NaN for NaN and numbers that are not larger than -1.
+    ///
+    /// ```
+    /// # use dec64::{Dec64, consts::*};
+    /// let tiny = Dec64::from_parts(1, -20);
+    /// assert_eq!(tiny.ln_1p(), tiny);
+    /// assert_eq!((ONE + tiny).ln(), ZERO);
+    /// ```
+    pub fn ln_1p(self) -> Dec64 {
+        if self.is_nan() || self <= NEGATIVE_ONE {
+            return NAN;
+        }
+        Extended::from_dec64(self).ln_1p().round()
+    }
+
+    /// Returns the base-10 logarithm, which is exact for powers of ten.
+    ///
+    /// Returns NaN for NaN, zero and negative numbers.
+    ///
+    /// ```
+    /// # use dec64::{Dec64, consts::*};
+    /// assert_eq!(Dec64::from(1000).log10(), THREE);
+    /// assert_eq!(CENT.log10(), NEGATIVE_TWO);
+    /// assert_eq!(TWO.log10(), Dec64::from_parts(3010299956639812, -16));
+    /// ```
+    pub fn log10(self) -> Dec64 {
+        if self.is_nan() || self.coefficient() <= 0 {
+            return NAN;
+        }
+        if let Some(exponent) = power_of_ten(self) {
+            return exponent.into();
+        }
+        Extended::from_dec64(self).ln().div(Extended::LN_10).round()
+    }
+
+    /// Returns the base-2 logarithm, which is exact for powers of two.
+    ///
+    /// Returns NaN for NaN, zero and negative numbers.
+    ///
+    /// ```
+    /// # use dec64::{Dec64, consts::*};
+    /// assert_eq!(Dec64::from(1024).log2(), TEN);
+    /// assert_eq!(Dec64::from_parts(125, -3).log2(), NEGATIVE_THREE);
+    /// assert_eq!(E.log2(), LOG2_E);
+    /// ```
+    pub fn log2(self) -> Dec64 {
+        if self.is_nan() || self.coefficient() <= 0 {
+            return NAN;
+        }
+        if let Some(exponent) = power_of_two(self) {
+            return exponent.into();
+        }
+        Extended::from_dec64(self).ln().div(Extended::LN_2).round()
+    }
+
+    /// Returns the logarithm to the given base.
+    ///
+    /// Bases ten and two are as exact as [`log10`](Dec64::log10) and [`log2`](Dec64::log2).
+    /// Returns NaN for NaN, if the number is not positive, and if the base is not positive or one.
+    ///
+    /// ```
+    /// # use dec64::{Dec64, consts::*};
+    /// assert_eq!(Dec64::from(81).log(THREE), FOUR);
+    /// assert_eq!(Dec64::from(1000).log(TEN), THREE);
+    /// ```
+    pub fn log(self, base: Dec64) -> Dec64 {
+        if self.is_nan()
+            || base.is_nan()
+            || self.coefficient() <= 0
+            || base.coefficient() <= 0
+            || base == ONE
+        {
+            return NAN;
+        }
+        if base == TEN {
+            return self.log10();
+        }
+        if base == TWO {
+            return self.log2();
+        }
+        if self == ONE {
+            return ZERO;
+        }
+        Extended::from_dec64(self)
+            .ln()
+            .div(Extended::from_dec64(base).ln())
+            .round()
+    }
+}
@@ -0,0 +1,445 @@
+//! Extended-precision intermediate results of powers, exponentials, logarithms and trigonometric functions.
+//!
+//! An [`Extended`] number keeps 38 significant digits, so that results computed with a handful of
+//! roundings are still accurate to far more digits than a DEC64 coefficient holds.
+//! Results are then rounded once to a DEC64. Exact intermediate results stay exact,
+//! so the final rounding is correct whenever no digits were lost on the way.
+
+use crate::{Dec64, RoundingMode, Wide};
+
+/// Number of digits of a normalized mantissa.
+const DIGITS: u32 = 38;
+/// The smallest normalized mantissa.
+const MIN_MANTISSA: u128 = 10u128.pow(DIGITS - 1);
+/// Mantissas are multiplied in two limbs of this base.
+const LIMB: u128 = 10u128.pow(DIGITS / 2);
+/// Exponents are clamped to this magnitude, which is far beyond the range of DEC64,
+/// so that repeated squaring can’t overflow them.
+const EXPONENT_LIMIT: i64 = 1_000_000;
+/// Number of times the argument of [`Extended::exp`] is halved before the Taylor series is summed.
+const EXP_HALVINGS: u32 = 8;
+
+/// A number with a 38-digit mantissa, `±mantissa × 10^exponent`.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Extended {
+    pub negative: bool,
+    /// The mantissa has exactly 38 digits, or is zero.
+    pub mantissa: u128,
+    pub exponent: i64,
+    /// Some nonzero digits were lost, so the number is only accurate to about 36 digits.
+    pub inexact: bool,
+}
+
+impl Extended {
+    pub const ZERO: Extended = Extended::constant(0, 0);
+    pub const ONE: Extended = Extended::constant(MIN_MANTISSA, 1 - DIGITS as i64);
+    pub const TWO: Extended = Extended::constant(2 * MIN_MANTISSA, 1 - DIGITS as i64);
+    pub const HALF: Extended = Extended::constant(5 * MIN_MANTISSA, -(DIGITS as i64));
+    const QUARTER: Extended = Extended::constant(25 * MIN_MANTISSA / 10, -(DIGITS as i64));
+    pub const LN_2: Extended = Extended {
+        inexact: true,
+        ..Extended::constant(69314718055994530941723212145817656807, -38)
+    };
+    pub const LN_10: Extended = Extended {
+        inexact: true,
+        ..Extended::constant(23025850929940456840179914546843642076, -37)
+    };
+    /// The square root of two, rounded up.
+    const SQRT_2: Extended = Extended::constant(14142135623730950488016887242096980786, -37);
+    /// The square root of one half, rounded down.
+    const SQRT_HALF: Extended = Extended::constant(70710678118654752440084436210484903928, -38);
+    /// The mantissa of the square root of ten.
+    const SQRT_10_MANTISSA: u128 = 31622776601683793319988935444327185337;
+
+    /// An exact positive number with an already normalized mantissa.
+    pub const fn constant(mantissa: u128, exponent: i64) -> Self {
+        Self {
+            negative: false,
+            mantissa,
+            exponent,
+            inexact: false,
+        }
+    }
+
+    /// Normalizes the mantissa to 38 digits, dropping digits beyond that.
+    pub fn new(negative: bool, mut mantissa: u128, mut exponent: i64, mut inexact: bool) -> Self {
+        if mantissa == 0 {
+            return Self {
+                inexact,
+                ..Self::ZERO
+            };
+        }
+        while mantissa >= 10 * MIN_MANTISSA {
+            inexact |= !mantissa.is_multiple_of(10);
+            mantissa /= 10;
+            exponent += 1;
+        }
+        while mantissa < MIN_MANTISSA {
+            mantissa *= 10;
+            exponent -= 1;
+        }
+        Self {
+            negative,
+            mantissa,
+            exponent: exponent.clamp(-EXPONENT_LIMIT, EXPONENT_LIMIT),
+            inexact,
+        }
+    }
+
+    /// The exact value of a non-NaN DEC64.
+    pub fn from_dec64(value: Dec64) -> Self {
+        let coefficient = value.coefficient();
+        Self::new(
+            coefficient < 0,
+            coefficient.unsigned_abs() as u128,
+            value.exponent() as i64,
+            false,
+        )
+    }
+
+    /// The exact value of an integer.
+    pub fn from_integer(integer: i64) -> Self {
+        Self::new(integer < 0, integer.unsigned_abs() as u128, 0, false)
+    }
+
+    /// `10^exponent`, as far as it stays within the clamped exponent range.
+    pub fn power_of_ten(exponent: i64) -> Self {
+        Self {
+            exponent: (exponent + 1 - DIGITS as i64).clamp(-EXPONENT_LIMIT, EXPONENT_LIMIT),
+            ..Self::ONE
+        }
+    }
+
+    #[inline]
+    pub fn is_zero(self) -> bool {
+        self.mantissa == 0
+    }
+
+    /// Whether the magnitude is less than the magnitude of `other`.
+    fn is_smaller(self, other: Extended) -> bool {
+        match (self.is_zero(), other.is_zero()) {
+            (_, true) => false,
+            (true, false) => true,
+            (false, false) => (self.exponent, self.mantissa) < (other.exponent, other.mantissa),
+        }
+    }
+
+    #[inline]
+    pub fn abs(self) -> Self {
+        Self {
+            negative: false,
+            ..self
+        }
+    }
+
+    #[inline]
+    pub fn neg(self) -> Self {
+        Self {
+            negative: !self.negative && !self.is_zero(),
+            ..self
+        }
+    }
+
+    /// Rounds to the nearest DEC64, with ties away from zero.
+    ///
+    /// Numbers that are too large become NaN, and numbers that are too small become zero.
+    pub fn round(self) -> Dec64 {
+        let coefficient = self.mantissa as i128;
+        Wide {
+            coefficient: if self.negative {
+                -coefficient
+            } else {
+                coefficient
+            },
+            // The clamped exponent is always in range.
+            exponent: self.exponent as i32,
+            sticky: self.inexact && !self.is_zero(),
+        }
+        .round(RoundingMode::HalfUp)
+        .0
+    }
+
+    /// Rounds to the nearest integer, with ties away from zero, or returns [`None`] if it doesn’t fit.
+    pub fn to_integer(self) -> Option<i64> {
+        if self.exponent >= 0 {
+            return if self.is_zero() { Some(0) } else { None };
+        }
+        let dropped = self.exponent.unsigned_abs();
+        if dropped > DIGITS as u64 {
+            return Some(0);
+        }
+        let divisor = 10u128.pow(dropped as u32);
+        let mut integer = self.mantissa / divisor;
+        if self.mantissa % divisor >= divisor.div_ceil(2) {
+            integer += 1;
+        }
+        let integer = i64::try_from(integer).ok()?;
+        Some(if self.negative { -integer } else { integer })
+    }
+
+    /// The sum, where digits of the smaller number below the larger number’s mantissa are cut off.
+    pub fn add(self, other: Extended) -> Self {
+        let (larger, smaller) = if self.is_smaller(other) {
+            (other, self)
+        } else {
+            (self, other)
+        };
+        if smaller.is_zero() {
+            return Self {
+                inexact: larger.inexact || smaller.inexact,
+                ..larger
+            };
+        }
+
+        let shift = (larger.exponent - smaller.exponent) as u64;
+        let (aligned, lost) = match 10u128.checked_pow(shift.min(u32::MAX as u64) as u32) {
+            Some(divisor) => (
+                smaller.mantissa / divisor,
+                !smaller.mantissa.is_multiple_of(divisor),
+            ),
+            None => (0, true),
+        };
+        let inexact = larger.inexact || smaller.inexact || lost;
+        if larger.negative == smaller.negative {
+            Self::new(
+                larger.negative,
+                larger.mantissa + aligned,
+                larger.exponent,
+                inexact,
+            )
+        } else {
+            Self::new(
+                larger.negative,
+                larger.mantissa - aligned,
+                larger.exponent,
+                inexact,
+            )
+        }
+    }
+
+    #[inline]
+    pub fn sub(self, other: Extended) -> Self {
+        self.add(other.neg())
+    }
+
+    /// The product, truncated to 38 digits.
+    pub fn mul(self, other: Extended) -> Self {
+        if self.is_zero() || other.is_zero() {
+            // A zero stays exact, unless it is only the truncation of another number.
+            let inexact = (self.is_zero() && self.inexact) || (other.is_zero() && other.inexact);
+            return Self {
+                inexact,
+                ..Self::ZERO
+            };
+        }
+
+        // Multiply in limbs of 19 digits: the product is high × LIMB² + middle × LIMB + low.
+        let (lhs_high, lhs_low) = (self.mantissa / LIMB, self.mantissa % LIMB);
+        let (rhs_high, rhs_low) = (other.mantissa / LIMB, other.mantissa % LIMB);
+        let low = lhs_low * rhs_low;
+        let middle = lhs_high * rhs_low + lhs_low * rhs_high + low / LIMB;
+        let high = lhs_high * rhs_high + middle / LIMB;
+        let (middle, low) = (middle % LIMB, low % LIMB);
+
+        // The product of two 38-digit mantissas has 75 or 76 digits, so `high` has 37 or 38 digits;
+        // take one more digit from the middle limb if needed.
+        let (mantissa, exponent, rest) = if high < MIN_MANTISSA {
+            let next = LIMB / 10;
+            (
+                high * 10 + middle / next,
+                self.exponent + other.exponent + DIGITS as i64 - 1,
+                middle % next != 0 || low != 0,
+            )
+        } else {
+            (
+                high,
+                self.exponent + other.exponent + DIGITS as i64,
+                middle != 0 || low != 0,
+            )
+        };
+        Self::new(
+            self.negative != other.negative,
+            mantissa,
+            exponent,
+            self.inexact || other.inexact || rest,
+        )
+    }
+
+    /// The quotient, where the divisor must not be zero. It is truncated to 38 digits.
+    pub fn div(self, other: Extended) -> Self {
+        if self.is_zero() {
+            return self;
+        }
+        // Use 37 digits of the divisor, so that ten times the remainder still fits.
+        let divisor = other.mantissa / 10;
+        let mut quotient = self.mantissa / divisor;
+        let mut remainder = self.mantissa % divisor;
+        let mut exponent = self.exponent - other.exponent - 1;
+        while quotient < MIN_MANTISSA {
+            remainder *= 10;
+            quotient = quotient * 10 + remainder / divisor;
+            remainder %= divisor;
+            exponent -= 1;
+        }
+        Self::new(
+            self.negative != other.negative,
+            quotient,
+            exponent,
+            self.inexact || other.inexact || !other.mantissa.is_multiple_of(10) || remainder != 0,
+        )
+    }
+
+    /// Whether adding the term can’t change the sum anymore.
+    fn is_negligible(term: Extended, sum: Extended) -> bool {
+        term.is_zero() || term.exponent + (DIGITS as i64) < sum.exponent
+    }
+
+    /// The exponential function `e^self`.
+    ///
+    /// Numbers far beyond the range of DEC64 are clamped to the limits of the exponent.
+    pub fn exp(self) -> Self {
+        if self.is_zero() && !self.inexact {
+            return Self::ONE;
+        }
+        // Magnitudes of at least 1000 certainly overflow or underflow.
+        if self.exponent + DIGITS as i64 > 3 {
+            let limit = if self.negative {
+                -EXPONENT_LIMIT
+            } else {
+                EXPONENT_LIMIT
+            };
+            return Self {
+                inexact: true,
+                ..Self::power_of_ten(limit)
+            };
+        }
+
+        // e^x = 10^k × e^r with r = x - k × ln(10), and |r| ≤ ln(10) / 2.
+        let k = self.div(Self::LN_10).to_integer().unwrap_or_default();
+        let reduced = self.sub(Self::LN_10.mul(Self::from_integer(k)));
+        // Halve the argument a few times to speed up the series, and square its sum that many times.
+        let reduced = reduced.div(Self::from_integer(1 << EXP_HALVINGS));
+        let mut result = reduced.exp_m1_series().add(Self::ONE);
+        for _ in 0..EXP_HALVINGS {
+            result = result.mul(result);
+        }
+        Self {
+            exponent: (result.exponent + k).clamp(-EXPONENT_LIMIT, EXPONENT_LIMIT),
+            ..result
+        }
+    }
+
+    /// `e^self - 1`, which is accurate for tiny numbers unlike subtracting one from [`exp`](Extended::exp).
+    pub fn exp_m1(self) -> Self {
+        // Magnitudes of at least one lose no digits when one is subtracted.
+        if self.exponent + DIGITS as i64 > 0 {
+            self.exp().sub(Self::ONE)
+        } else {
+            self.exp_m1_series()
+        }
+    }
+
+    /// `e^x - 1 = x + x²/2! + x³/3! + …` for `|x| < 1`.
+    fn exp_m1_series(self) -> Self {
+        let mut sum = self;
+        let mut term = self;
+        for k in 2.. {
+            term = term.mul(self).div(Self::from_integer(k));
+            if Self::is_negligible(term, sum) {
+                break;
+            }
+            sum = sum.add(term);
+        }
+        sum
+    }
+
+    /// The sine and cosine of a number in `[-π/4, π/4]`.
+    pub fn sin_cos(self) -> (Self, Self) {
+        let square = self.mul(self);
+        // sin(x) = x - x³/3! + x⁵/5! - …
+        let mut sine = self;
+        let mut term = self;
+        for k in 1.. {
+            term = term
+                .mul(square)
+                .div(Self::from_integer(2 * k * (2 * k + 1)))
+                .neg();
+            if Self::is_negligible(term, sine) {
+                break;
+            }
+            sine = sine.add(term);
+        }
+        // cos(x) = 1 - x²/2! + x⁴/4! - …
+        let mut cosine = Self::ONE;
+        let mut term = Self::ONE;
+        for k in 1.. {
+            term = term
+                .mul(square)
+                .div(Self::from_integer((2 * k - 1) * 2 * k))
+                .neg();
+            if Self::is_negligible(term, cosine) {
+                break;
+            }
+            cosine = cosine.add(term);
+        }
+        (sine, cosine)
+    }
+
+    /// The natural logarithm, where the number must be positive.
+    pub fn ln(self) -> Self {
+        // x = f × 10^tens with f in [1/√10, √10)…
+        let mut tens = self.exponent + DIGITS as i64 - 1;
+        let mut fraction = Self {
+            exponent: 1 - DIGITS as i64,
+            ..self
+        };
+        if fraction.mantissa >= Self::SQRT_10_MANTISSA {
+            fraction.exponent -= 1;
+            tens += 1;
+        }
+        // …and f = g × 2^twos with g in [1/√2, √2).
+        let mut twos = 0;
+        while !fraction.is_smaller(Self::SQRT_2) {
+            fraction = fraction.mul(Self::HALF);
+            twos += 1;
+        }
+        while fraction.is_smaller(Self::SQRT_HALF) {
+            fraction = fraction.mul(Self::TWO);
+            twos -= 1;
+        }
+
+        // ln(g) = 2 atanh(z) with z = (g - 1) / (g + 1).
+        let z = fraction.sub(Self::ONE).div(fraction.add(Self::ONE));
+        Self::from_integer(tens)
+            .mul(Self::LN_10)
+            .add(Self::from_integer(twos).mul(Self::LN_2))
+            .add(z.double_atanh())
+    }
+
+    /// The natural logarithm of `1 + self`, where the number must be larger than -1.
+    ///
+    /// Unlike [`ln`](Extended::ln), this is accurate for tiny numbers whose sum with one has too many digits.
+    pub fn ln_1p(self) -> Self {
+        if !self.abs().is_smaller(Self::QUARTER) {
+            return Self::ONE.add(self).ln();
+        }
+        // ln(1 + x) = 2 atanh(z) with z = x / (2 + x), where 1 + x is within [1/√2, √2).
+        self.div(Self::TWO.add(self)).double_atanh()
+    }
+
+    /// `2 atanh(z) = ln((1 + z) / (1 - z)) = 2 (z + z³/3 + z⁵/5 + …)` for `|z| < 0.18`.
+    fn double_atanh(self) -> Self {
+        let square = self.mul(self);
+        let mut atanh = self;
+        let mut power = self;
+        for k in 1.. {
+            power = power.mul(square);
+            let term = power.div(Self::from_integer(2 * k + 1));
+            if Self::is_negligible(term, atanh) {
+                break;
+            }
+            atanh = atanh.add(term);
+        }
+        atanh.mul(Self::TWO)
+    }
+}
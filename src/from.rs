@@ -1,185 +1,203 @@
-use core::f32;
+use core::fmt::{self, Display, Formatter};
 
-use crate::Dec64;
+use crate::{Dec64, Flags, MAX_COEFFICIENT, RoundingMode, Wide};
 
-mod diyfp;
-mod grisu2;
+mod from_float;
+mod to_float;
 
-/// Converts an exponent to its corresponding power as a binary floating-point number.
-fn exponent_to_power_f64(e: i8) -> f64 {
-    const POSITIVE_POWERS: [f64; 23] = [
-        1.0, 1e1, 1e2, 1e3, 1e4, 1e5, 1e6, 1e7, 1e8, 1e9, 1e10, 1e11, 1e12, 1e13, 1e14, 1e15, 1e16,
-        1e17, 1e18, 1e19, 1e20, 1e21, 1e22,
-    ];
-
-    const NEGATIVE_POWERS: [f64; 23] = [
-        1.0, 1e-1, 1e-2, 1e-3, 1e-4, 1e-5, 1e-6, 1e-7, 1e-8, 1e-9, 1e-10, 1e-11, 1e-12, 1e-13,
-        1e-14, 1e-15, 1e-16, 1e-17, 1e-18, 1e-19, 1e-20, 1e-21, 1e-22,
-    ];
-
-    let index = e.unsigned_abs() as usize;
+macro_rules! impl_integer_small {
+    ($( $t:ty ),*) => ($(
+        impl From<$t> for Dec64 {
+            fn from(num: $t) -> Dec64 {
+                Dec64::from_raw((num as i64) << 8)
+            }
+        }
+    )*)
+}
 
-    if index < 23 {
-        if e < 0 {
-            NEGATIVE_POWERS[index]
-        } else {
-            POSITIVE_POWERS[index]
+impl Dec64 {
+    /// Converts an integer given as sign and magnitude, rounding once if it has too many digits.
+    fn from_integer(negative: bool, magnitude: u128) -> (Dec64, Flags) {
+        if magnitude <= MAX_COEFFICIENT as u128 {
+            let coefficient = magnitude as i64;
+            let coefficient = if negative { -coefficient } else { coefficient };
+            return (Dec64::from_parts(coefficient, 0), Flags::empty());
         }
-    } else {
-        // powf is more accurate
-        10f64.powf(e as f64)
+        // Magnitudes that don’t fit an i128 lose their last digit, which is far below the precision
+        // of a DEC64 and therefore only matters for rounding if it is nonzero.
+        let wide = match i128::try_from(magnitude) {
+            Ok(coefficient) => Wide::exact(coefficient, 0),
+            Err(_) => Wide {
+                coefficient: (magnitude / 10) as i128,
+                exponent: 1,
+                sticky: !magnitude.is_multiple_of(10),
+            },
+        };
+        let wide = if negative {
+            Wide {
+                coefficient: -wide.coefficient,
+                ..wide
+            }
+        } else {
+            wide
+        };
+        wide.round(RoundingMode::HalfUp)
     }
 }
 
-fn exponent_to_power_f32(e: i8) -> f32 {
-    const POSITIVE_POWERS: [f32; 16] = [
-        1.0, 1e1, 1e2, 1e3, 1e4, 1e5, 1e6, 1e7, 1e8, 1e9, 1e10, 1e11, 1e12, 1e13, 1e14, 1e15,
-    ];
-
-    const NEGATIVE_POWERS: [f32; 16] = [
-        1.0, 1e-1, 1e-2, 1e-3, 1e-4, 1e-5, 1e-6, 1e-7, 1e-8, 1e-9, 1e-10, 1e-11, 1e-12, 1e-13,
-        1e-14, 1e-15,
-    ];
-
-    let index = e.unsigned_abs() as usize;
+/// Conversions from integers that may have more digits than a DEC64 coefficient.
+/// They round half away from zero, like [`Dec64::new`].
+macro_rules! impl_integer_large {
+    ($sign_magnitude:expr; $( $t:ty, $exact:ident );*) => ($(
+        impl From<$t> for Dec64 {
+            // The cast is a no-op for 128-bit integers.
+            #[allow(clippy::unnecessary_cast)]
+            fn from(num: $t) -> Dec64 {
+                let (negative, magnitude) = $sign_magnitude(num);
+                Dec64::from_integer(negative, magnitude).0
+            }
+        }
 
-    if index < 16 {
-        if e < 0 {
-            NEGATIVE_POWERS[index]
-        } else {
-            POSITIVE_POWERS[index]
+        impl Dec64 {
+            #[doc = concat!("Converts an [`", stringify!($t), "`] exactly, or returns [`None`] if it has more digits than a DEC64 can hold.")]
+            ///
+            /// [`From`] rounds such integers instead.
+            #[inline]
+            #[allow(clippy::unnecessary_cast)]
+            pub fn $exact(num: $t) -> Option<Dec64> {
+                let (negative, magnitude) = $sign_magnitude(num);
+                match Dec64::from_integer(negative, magnitude) {
+                    (dec, flags) if flags.is_empty() => Some(dec),
+                    _ => None,
+                }
+            }
         }
-    } else {
-        // powf is more accurate
-        10f32.powf(e as f32)
-    }
+    )*)
 }
 
-impl From<Dec64> for f64 {
-    fn from(dec: Dec64) -> f64 {
-        (dec.coefficient() as f64) * exponent_to_power_f64(dec.exponent())
-    }
+impl_integer_small!(u8, u16, u32, i8, i16, i32);
+impl_integer_large!(
+    |num| (num < 0, (num as i128).unsigned_abs());
+    i64, from_i64_exact; i128, from_i128_exact; isize, from_isize_exact
+);
+impl_integer_large!(
+    |num| (false, num as u128);
+    u64, from_u64_exact; u128, from_u128_exact; usize, from_usize_exact
+);
+
+/// An error which can be returned when converting a [`Dec64`] to an integer type.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TryFromDec64Error {
+    /// The Dec64 was NaN.
+    Nan,
+    /// The Dec64 was outside the range of the integer type.
+    OutOfRange,
+    /// The Dec64 was not an integer.
+    Fractional,
 }
 
-impl From<Dec64> for f32 {
-    fn from(dec: Dec64) -> f32 {
-        (dec.coefficient() as f32) * exponent_to_power_f32(dec.exponent())
+impl Display for TryFromDec64Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Nan => f.write_str("cannot convert DEC64 NaN to integer"),
+            Self::OutOfRange => f.write_str("DEC64 out of range of integer type"),
+            Self::Fractional => f.write_str("DEC64 has a fractional part"),
+        }
     }
 }
 
-impl From<f64> for Dec64 {
-    fn from(float: f64) -> Dec64 {
-        if float < 0.0 {
-            let (coefficient, exponent) = grisu2::convert(-float);
-
-            Dec64::from_parts(-(coefficient as i64), exponent as i8)
-        } else {
-            let (coefficient, exponent) = grisu2::convert(float);
+impl core::error::Error for TryFromDec64Error {}
+
+/// An error which can be returned when converting a floating-point number to a [`Dec64`],
+/// see [`Dec64::try_from_f64`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TryFromFloatError {
+    /// The float was NaN.
+    Nan,
+    /// The float was positive or negative infinity.
+    Infinite,
+    /// The float was outside the range of Dec64.
+    OutOfRange,
+}
 
-            Dec64::from_parts(coefficient as i64, exponent as i8)
+impl Display for TryFromFloatError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Nan => f.write_str("cannot convert float NaN to DEC64"),
+            Self::Infinite => f.write_str("cannot convert infinite float to DEC64"),
+            Self::OutOfRange => f.write_str("float out of range of DEC64"),
         }
     }
 }
 
-impl From<f32> for Dec64 {
-    fn from(float: f32) -> Dec64 {
-        if float < 0.0 {
-            let (coefficient, exponent) = grisu2::convert(-float as f64);
-
-            Dec64::from_parts(-(coefficient as i64), exponent as i8)
-        } else {
-            let (coefficient, exponent) = grisu2::convert(float as f64);
+impl core::error::Error for TryFromFloatError {}
 
-            Dec64::from_parts(coefficient as i64, exponent as i8)
+impl Dec64 {
+    /// Returns the sign and magnitude of an integral DEC64.
+    fn integer_parts(self) -> Result<(bool, u128), TryFromDec64Error> {
+        if self.is_nan() {
+            return Err(TryFromDec64Error::Nan);
         }
-    }
-}
-
-macro_rules! impl_integer_small {
-    ($( $t:ty ),*) => ($(
-        impl From<$t> for Dec64 {
-            fn from(num: $t) -> Dec64 {
-                Dec64::from_raw((num as i64) << 8)
-            }
+        let negative = self.coefficient() < 0;
+        let magnitude = self.coefficient().unsigned_abs() as u128;
+        let exponent = self.exponent();
+        if magnitude == 0 {
+            return Ok((false, 0));
         }
-
-        impl From<Dec64> for $t {
-            fn from(dec: Dec64) -> $t {
-                let exponent = dec.exponent();
-
-                if exponent <= 0 {
-                    dec.coefficient() as $t
-                } else {
-                    // This may overflow, which is fine
-                    (dec.coefficient() * 10i64.pow(exponent as u32)) as $t
-                }
+        if exponent >= 0 {
+            10u128
+                .checked_pow(exponent as u32)
+                .and_then(|power| power.checked_mul(magnitude))
+                .map(|magnitude| (negative, magnitude))
+                .ok_or(TryFromDec64Error::OutOfRange)
+        } else {
+            // A nonzero coefficient has less than 39 digits, so larger powers leave a fraction.
+            match 10u128.checked_pow(exponent.unsigned_abs() as u32) {
+                Some(power) if magnitude.is_multiple_of(power) => Ok((negative, magnitude / power)),
+                _ => Err(TryFromDec64Error::Fractional),
             }
         }
-    )*)
+    }
 }
 
-macro_rules! impl_integer_large_signed {
+/// Converts integral DEC64 values exactly.
+/// Fails if the DEC64 is NaN, has a fractional part, or is out of range for the integer type.
+macro_rules! impl_try_from_dec64 {
     ($( $t:ty ),*) => ($(
-        impl From<$t> for Dec64 {
-            fn from(num: $t) -> Dec64 {
-                let mut adjusted_num = num;
-                let mut last_digit = 0;
-                let mut exponent = 0;
-                while (adjusted_num > i64::MAX as $t) || (adjusted_num < i64::MIN as $t) {
-                    last_digit = (adjusted_num % 10).abs();
-                    adjusted_num /= 10;
-                    exponent += 1;
-                }
-                Dec64::new((adjusted_num + if last_digit >= 5 { adjusted_num.signum() } else { 0 }) as i64, exponent)
-            }
-        }
-
-        impl From<Dec64> for $t {
-            fn from(dec: Dec64) -> $t {
-                let exponent = dec.exponent();
-
-                if exponent <= 0 {
-                    dec.coefficient() as $t
+        impl TryFrom<Dec64> for $t {
+            type Error = TryFromDec64Error;
+
+            fn try_from(dec: Dec64) -> Result<$t, TryFromDec64Error> {
+                let (negative, magnitude) = dec.integer_parts()?;
+                let integer = if negative {
+                    0i128
+                        .checked_sub_unsigned(magnitude)
+                        .and_then(|integer| <$t>::try_from(integer).ok())
                 } else {
-                    // This may overflow, which is fine
-                    (dec.coefficient() * 10i64.pow(exponent as u32)) as $t
-                }
+                    <$t>::try_from(magnitude).ok()
+                };
+                integer.ok_or(TryFromDec64Error::OutOfRange)
             }
         }
     )*)
 }
 
-macro_rules! impl_integer_large_unsigned {
-    ($( $t:ty ),*) => ($(
-        impl From<$t> for Dec64 {
-            fn from(num: $t) -> Dec64 {
-                let mut adjusted_num = num;
-                let mut last_digit = 0;
-                let mut exponent = 0;
-                while adjusted_num > i64::MAX as $t {
-                    last_digit = adjusted_num % 10;
-                    adjusted_num /= 10;
-                    exponent += 1;
-                }
-                Dec64::new((adjusted_num + if last_digit >= 5 { 1 } else { 0 }) as i64, exponent)
-            }
-        }
-
-        impl From<Dec64> for $t {
-            fn from(dec: Dec64) -> $t {
-                let exponent = dec.exponent();
-
-                if exponent <= 0 {
-                    dec.coefficient() as $t
-                } else {
-                    // This may overflow, which is fine
-                    (dec.coefficient() * 10i64.pow(exponent as u32)) as $t
-                }
+impl_try_from_dec64!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+);
+
+macro_rules! impl_to_integer_rounded {
+    ($( $t:ty, $method:ident );*) => ($(
+        impl Dec64 {
+            #[doc = concat!("Rounds to an integer with the given rounding mode and converts it to [`", stringify!($t), "`].")]
+            ///
+            /// Fails if the DEC64 is NaN or the rounded integer is out of range.
+            #[inline]
+            pub fn $method(self, mode: RoundingMode) -> Result<$t, TryFromDec64Error> {
+                self.round_to_places_with(0, mode).try_into()
             }
         }
     )*)
 }
 
-impl_integer_small!(u8, u16, u32, i8, i16, i32);
-impl_integer_large_signed!(isize, i64, i128);
-impl_integer_large_unsigned!(usize, u64, u128);
+impl_to_integer_rounded!(i64, to_i64_rounded; u64, to_u64_rounded; i128, to_i128_rounded; u128, to_u128_rounded);
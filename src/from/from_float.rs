@@ -0,0 +1,278 @@
+//! Conversion from binary floating-point numbers to DEC64.
+//!
+//! The shortest decimal that round-trips to the same float is used whenever it fits into a DEC64.
+//! Otherwise, the exact value of the float is rounded to the nearest DEC64: the float is formatted
+//! to one more digit than a DEC64 coefficient holds, and compared exactly with that decimal to break ties.
+//! Both digit sequences are produced by the float formatting of the core library,
+//! which implements Grisu with a Dragon4 fallback.
+
+use core::cmp::Ordering;
+use core::fmt::{self, LowerExp, Write};
+use core::str;
+
+use crate::{Dec64, NAN, RoundingMode, TryFromFloatError, Wide, ZERO};
+
+/// Number of digits kept in the coefficient of the wide intermediate result. Further digits only contribute to rounding.
+const KEPT_DIGITS: u32 = 37;
+/// Number of fractional digits of the nearest decimal that the exact value is compared with.
+/// Together with the leading digit, these are more digits than a DEC64 coefficient holds.
+const NEAREST_PRECISION: usize = 17;
+/// Length of the nearest decimal: sign, leading digit, point, fractional digits, and a signed three-digit exponent.
+const NEAREST_LENGTH: usize = NEAREST_PRECISION + 8;
+/// Floats whose leading digit is above this power of ten are beyond [`MAX`](crate::MAX).
+const MAX_LEADING_EXPONENT: i32 = 143;
+/// Floats whose leading digit is below this power of ten round to zero.
+const MIN_LEADING_EXPONENT: i32 = -128;
+/// Number of 64-bit limbs of [`Big`], which holds the exact values of floats within the range of DEC64
+/// scaled to integers by powers of two and five.
+const LIMBS: usize = 14;
+
+/// A fixed-size unsigned integer for exactly comparing a float with a decimal.
+#[derive(PartialEq, Eq)]
+struct Big([u64; LIMBS]);
+
+impl Big {
+    fn new(number: u128) -> Self {
+        let mut limbs = [0; LIMBS];
+        limbs[0] = number as u64;
+        limbs[1] = (number >> 64) as u64;
+        Self(limbs)
+    }
+
+    fn mul_small(&mut self, factor: u64) {
+        let mut carry = 0;
+        for limb in &mut self.0 {
+            let product = *limb as u128 * factor as u128 + carry;
+            *limb = product as u64;
+            carry = product >> 64;
+        }
+        debug_assert_eq!(carry, 0);
+    }
+
+    /// Multiplies by `5^exponent`.
+    fn mul_pow5(&mut self, mut exponent: u32) {
+        // The largest power of five that fits into a limb.
+        const STEP: u32 = 27;
+        while exponent > 0 {
+            let step = exponent.min(STEP);
+            self.mul_small(5u64.pow(step));
+            exponent -= step;
+        }
+    }
+
+    /// Multiplies by `2^exponent`.
+    fn shl(&mut self, exponent: u32) {
+        let limbs = (exponent / 64) as usize;
+        let bits = exponent % 64;
+        debug_assert!(self.0[LIMBS - limbs..].iter().all(|&limb| limb == 0));
+        self.0.rotate_right(limbs);
+        if bits > 0 {
+            for index in (1..LIMBS).rev() {
+                self.0[index] = self.0[index] << bits | self.0[index - 1] >> (64 - bits);
+            }
+            self.0[0] <<= bits;
+        }
+    }
+}
+
+impl PartialOrd for Big {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Big {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.iter().rev().cmp(other.0.iter().rev())
+    }
+}
+
+/// Binary floating-point numbers that can be split into an integer mantissa and a binary exponent.
+trait Float: LowerExp {
+    /// Returns `(mantissa, exponent)` of the magnitude `mantissa × 2^exponent` of a finite float.
+    fn decompose(self) -> (u64, i32);
+}
+
+macro_rules! impl_float {
+    ($( $t:ty );*) => ($(
+        impl Float for $t {
+            fn decompose(self) -> (u64, i32) {
+                const FRACTION_BITS: u32 = <$t>::MANTISSA_DIGITS - 1;
+                const BIAS: i32 = <$t>::MAX_EXP - 1;
+                let bits = self.to_bits() as u64;
+                let fraction = bits & ((1 << FRACTION_BITS) - 1);
+                let biased = ((bits >> FRACTION_BITS) & (2 * BIAS as u64 + 1)) as i32;
+                if biased == 0 {
+                    // Subnormal numbers have no implicit leading bit.
+                    (fraction, 1 - BIAS - FRACTION_BITS as i32)
+                } else {
+                    (fraction | 1 << FRACTION_BITS, biased - BIAS - FRACTION_BITS as i32)
+                }
+            }
+        }
+    )*)
+}
+
+impl_float!(f64; f32);
+
+/// Makes the correctly rounded decimal `nearest` of the float `mantissa × 2^exponent` exact enough to be rounded again,
+/// by comparing it exactly with the float. `nearest` must have more digits than a DEC64 coefficient.
+fn exact_wide(nearest: Wide, mantissa: u64, exponent: i32) -> Wide {
+    // Compare m × 2^e with d × 2^q × 5^q, after scaling both to integers.
+    let twos = exponent.min(nearest.exponent);
+    let fives = nearest.exponent.min(0);
+    let mut float = Big::new(mantissa as u128);
+    float.shl((exponent - twos) as u32);
+    float.mul_pow5(fives.unsigned_abs());
+    let mut decimal = Big::new(nearest.coefficient.unsigned_abs());
+    decimal.shl((nearest.exponent - twos) as u32);
+    decimal.mul_pow5((nearest.exponent - fives) as u32);
+
+    match float.cmp(&decimal) {
+        Ordering::Equal => nearest,
+        // The float is within half a unit of the decimal, so it lies within one unit above or below.
+        Ordering::Greater => Wide {
+            sticky: true,
+            ..nearest
+        },
+        Ordering::Less => Wide {
+            coefficient: nearest.coefficient - nearest.coefficient.signum(),
+            sticky: true,
+            ..nearest
+        },
+    }
+}
+
+/// A fixed-size buffer that text can be formatted into.
+struct Buffer<const N: usize> {
+    bytes: [u8; N],
+    length: usize,
+}
+
+impl<const N: usize> Buffer<N> {
+    fn new() -> Self {
+        Self {
+            bytes: [0; N],
+            length: 0,
+        }
+    }
+
+    /// Parses the scientific notation written by the [`LowerExp`](fmt::LowerExp) implementation of floats.
+    fn to_wide(&self) -> Wide {
+        let text = &self.bytes[..self.length];
+        let (negative, text) = match text.split_first() {
+            Some((b'-', rest)) => (true, rest),
+            _ => (false, text),
+        };
+        let split = text
+            .iter()
+            .position(|&byte| byte == b'e')
+            .unwrap_or(text.len());
+        let (mantissa, exponent) = text.split_at(split);
+        let exponent = str::from_utf8(exponent.get(1..).unwrap_or_default())
+            .ok()
+            .and_then(|exponent| exponent.parse::<i32>().ok())
+            .unwrap_or_default();
+
+        let mut coefficient = 0i128;
+        let mut kept = 0;
+        let mut sticky = false;
+        for digit in mantissa.iter().filter(|byte| byte.is_ascii_digit()) {
+            if kept < KEPT_DIGITS {
+                coefficient = coefficient * 10 + (digit - b'0') as i128;
+                kept += 1;
+            } else {
+                sticky |= *digit != b'0';
+            }
+        }
+        Wide {
+            coefficient: if negative { -coefficient } else { coefficient },
+            // There is one digit before the point.
+            exponent: exponent - (kept as i32 - 1),
+            sticky,
+        }
+    }
+}
+
+impl<const N: usize> Write for Buffer<N> {
+    fn write_str(&mut self, text: &str) -> fmt::Result {
+        let end = self.length + text.len();
+        self.bytes
+            .get_mut(self.length..end)
+            .ok_or(fmt::Error)?
+            .copy_from_slice(text.as_bytes());
+        self.length = end;
+        Ok(())
+    }
+}
+
+/// Converts a finite float to the shortest DEC64 that converts back to the same float,
+/// or the nearest DEC64 if the shortest decimal has too many digits. Returns NaN if the float is out of range.
+fn from_finite(float: impl Float) -> Dec64 {
+    // At most 17 significant digits and a three-digit exponent.
+    let mut shortest = Buffer::<24>::new();
+    if write!(shortest, "{float:e}").is_err() {
+        return NAN;
+    }
+    let shortest = shortest.to_wide();
+    if shortest.coefficient == 0 {
+        return ZERO;
+    }
+    let leading_exponent = shortest.exponent + shortest.coefficient.unsigned_abs().ilog10() as i32;
+    if leading_exponent > MAX_LEADING_EXPONENT {
+        return NAN;
+    }
+    if leading_exponent < MIN_LEADING_EXPONENT {
+        return ZERO;
+    }
+    let (result, flags) = shortest.round(RoundingMode::HalfUp);
+    if flags.is_empty() {
+        return result;
+    }
+
+    let mut nearest = Buffer::<NEAREST_LENGTH>::new();
+    if write!(nearest, "{float:.NEAREST_PRECISION$e}").is_err() {
+        return NAN;
+    }
+    let (mantissa, exponent) = float.decompose();
+    exact_wide(nearest.to_wide(), mantissa, exponent)
+        .round(RoundingMode::HalfUp)
+        .0
+}
+
+macro_rules! impl_from_float {
+    ($( $t:ty, $try_from:ident );*) => ($(
+        impl From<$t> for Dec64 {
+            /// Converts to the shortest DEC64 that converts back to the same float.
+            ///
+            /// If the shortest decimal has too many digits, the float is rounded to the nearest DEC64,
+            /// with ties away from zero. NaN, infinities and numbers beyond the range of DEC64 become [`NAN`].
+            #[inline]
+            fn from(float: $t) -> Dec64 {
+                Dec64::$try_from(float).unwrap_or(NAN)
+            }
+        }
+
+        impl Dec64 {
+            #[doc = concat!("Converts an [`", stringify!($t), "`] like [`From`], but fails instead of returning NaN.")]
+            ///
+            /// Fails for NaN, infinities and numbers beyond the range of DEC64.
+            pub fn $try_from(float: $t) -> Result<Dec64, TryFromFloatError> {
+                if float.is_nan() {
+                    return Err(TryFromFloatError::Nan);
+                }
+                if float.is_infinite() {
+                    return Err(TryFromFloatError::Infinite);
+                }
+                let result = from_finite(float);
+                if result.is_nan() {
+                    Err(TryFromFloatError::OutOfRange)
+                } else {
+                    Ok(result)
+                }
+            }
+        }
+    )*)
+}
+
+impl_from_float!(f64, try_from_f64; f32, try_from_f32);
@@ -0,0 +1,124 @@
+//! Correctly rounded conversion from DEC64 to binary floating-point numbers.
+//!
+//! Most numbers with a short coefficient and a small exponent take Clinger’s fast path:
+//! the coefficient and the power of ten are both exact, so a single multiplication or division rounds only once.
+//! All other numbers go through the float parser of the core library, which implements Eisel–Lemire
+//! with an arbitrary-precision fallback and therefore always rounds correctly.
+
+use core::str;
+
+use crate::Dec64;
+
+/// Powers of ten that are exact in an [`f64`].
+const POWERS_F64: [f64; 23] = [
+    1.0, 1e1, 1e2, 1e3, 1e4, 1e5, 1e6, 1e7, 1e8, 1e9, 1e10, 1e11, 1e12, 1e13, 1e14, 1e15, 1e16,
+    1e17, 1e18, 1e19, 1e20, 1e21, 1e22,
+];
+
+/// Powers of ten that are exact in an [`f32`].
+const POWERS_F32: [f32; 11] = [1.0, 1e1, 1e2, 1e3, 1e4, 1e5, 1e6, 1e7, 1e8, 1e9, 1e10];
+
+/// Length of the longest scientific notation: sign, 17 digits, `e`, and a signed three-digit exponent.
+const SCIENTIFIC_LENGTH: usize = 23;
+
+/// Writes a non-NaN number as `<coefficient>e<exponent>` into the buffer, and returns the written part.
+fn scientific(value: Dec64, buffer: &mut [u8; SCIENTIFIC_LENGTH]) -> &str {
+    fn push_digits(buffer: &mut [u8], end: usize, mut number: u64) -> usize {
+        let mut start = end;
+        loop {
+            start -= 1;
+            buffer[start] = b'0' + (number % 10) as u8;
+            number /= 10;
+            if number == 0 {
+                return start;
+            }
+        }
+    }
+
+    // Fill the buffer back to front.
+    let mut start = push_digits(
+        buffer,
+        SCIENTIFIC_LENGTH,
+        value.exponent().unsigned_abs() as u64,
+    );
+    if value.exponent() < 0 {
+        start -= 1;
+        buffer[start] = b'-';
+    }
+    start -= 1;
+    buffer[start] = b'e';
+    start = push_digits(buffer, start, value.coefficient().unsigned_abs());
+    if value.coefficient() < 0 {
+        start -= 1;
+        buffer[start] = b'-';
+    }
+    // Only ASCII was written.
+    str::from_utf8(&buffer[start..]).unwrap_or_default()
+}
+
+macro_rules! impl_to_float {
+    ($t:ident, $powers:ident) => {
+        impl From<Dec64> for $t {
+            /// Converts to the nearest floating-point number, with ties to even.
+            ///
+            /// NaN becomes NaN, and numbers beyond the float’s range become infinite.
+            fn from(dec: Dec64) -> $t {
+                if dec.is_nan() {
+                    return $t::NAN;
+                }
+                let coefficient = dec.coefficient();
+                let exponent = dec.exponent();
+                let index = exponent.unsigned_abs() as usize;
+                if coefficient.unsigned_abs() <= 1 << $t::MANTISSA_DIGITS && index < $powers.len() {
+                    return if exponent < 0 {
+                        coefficient as $t / $powers[index]
+                    } else {
+                        coefficient as $t * $powers[index]
+                    };
+                }
+
+                let mut buffer = [0; SCIENTIFIC_LENGTH];
+                // The scientific notation is always valid.
+                scientific(dec, &mut buffer).parse().unwrap_or($t::NAN)
+            }
+        }
+    };
+}
+
+impl_to_float!(f64, POWERS_F64);
+impl_to_float!(f32, POWERS_F32);
+
+impl Dec64 {
+    /// Converts to an [`f64`] if the number is exactly representable, and returns [`None`] otherwise or for NaN.
+    ///
+    /// ```
+    /// # use dec64::{Dec64, consts::*};
+    /// assert_eq!(HALF.to_f64_exact(), Some(0.5));
+    /// assert_eq!(TENTH.to_f64_exact(), None);
+    /// ```
+    pub fn to_f64_exact(self) -> Option<f64> {
+        if self.is_nan() {
+            return None;
+        }
+        let magnitude = self.coefficient().unsigned_abs();
+        if magnitude == 0 {
+            return Some(0.0);
+        }
+        let exponent = self.exponent();
+        // The number is exact if the odd part of its numerator fits into the mantissa, and its denominator is a power of two.
+        // The exponent range of f64 is large enough for all DEC64 numbers.
+        let odd_numerator = if exponent >= 0 {
+            5u128
+                .checked_pow(exponent as u32)?
+                .checked_mul((magnitude >> magnitude.trailing_zeros()) as u128)?
+        } else {
+            let power = 5u64.checked_pow(exponent.unsigned_abs() as u32)?;
+            if !magnitude.is_multiple_of(power) {
+                return None;
+            }
+            let numerator = magnitude / power;
+            (numerator >> numerator.trailing_zeros()) as u128
+        };
+        (odd_numerator < 1 << f64::MANTISSA_DIGITS).then(|| self.into())
+    }
+}
@@ -0,0 +1,37 @@
+//! Reading and writing the raw DEC64 representation with [`std::io`].
+
+use std::io::{self, Read, Write};
+
+use crate::Dec64;
+
+impl Dec64 {
+    /// Reads a Dec64 in big-endian (network) byte order, see [`Dec64::from_be_bytes`].
+    ///
+    /// ```
+    /// # use dec64::{Dec64, consts::*};
+    /// let mut bytes: &[u8] = &[0, 0, 0, 0, 0, 0, 1, 0];
+    /// assert_eq!(Dec64::read_be(&mut bytes).unwrap(), ONE);
+    /// ```
+    pub fn read_be<R: Read + ?Sized>(reader: &mut R) -> io::Result<Dec64> {
+        let mut bytes = [0; 8];
+        reader.read_exact(&mut bytes)?;
+        Ok(Dec64::from_be_bytes(bytes))
+    }
+
+    /// Reads a Dec64 in little-endian byte order, see [`Dec64::from_le_bytes`].
+    pub fn read_le<R: Read + ?Sized>(reader: &mut R) -> io::Result<Dec64> {
+        let mut bytes = [0; 8];
+        reader.read_exact(&mut bytes)?;
+        Ok(Dec64::from_le_bytes(bytes))
+    }
+
+    /// Writes this number in big-endian (network) byte order, see [`Dec64::to_be_bytes`].
+    pub fn write_be<W: Write + ?Sized>(self, writer: &mut W) -> io::Result<()> {
+        writer.write_all(&self.to_be_bytes())
+    }
+
+    /// Writes this number in little-endian byte order, see [`Dec64::to_le_bytes`].
+    pub fn write_le<W: Write + ?Sized>(self, writer: &mut W) -> io::Result<()> {
+        writer.write_all(&self.to_le_bytes())
+    }
+}
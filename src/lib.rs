@@ -1,15 +1,40 @@
 use consts::*;
+use wide::Wide;
 
+mod checked;
 mod cmp;
 pub mod consts;
-#[cfg(feature = "std")]
+mod context;
+mod exact;
+mod exp;
+mod extended;
 mod fmt;
 mod from;
+#[cfg(feature = "std")]
+mod io;
 mod math;
+mod mixed;
+#[cfg(feature = "num-traits")]
+mod num;
 mod ops;
+mod parse;
+mod pow;
+mod rem;
+mod root;
+mod rounding;
+mod saturating;
+#[cfg(feature = "serde")]
+pub mod serde;
+mod trig;
+mod wide;
 #[cfg(feature = "std")]
 mod write;
 
+pub use context::{Context, Flags, Trapped};
+pub use from::{TryFromDec64Error, TryFromFloatError};
+pub use parse::ParseDec64Error;
+pub use rounding::RoundingMode;
+
 /// Minimum value of DEC64 coefficient.
 pub const MIN_COEFFICIENT: i64 = -0x80000000000000;
 /// Maximum value of DEC64 coefficient.
@@ -62,9 +87,15 @@ const POWERS_OF_10: [u64; 20] = [
 ///
 /// Dec64 implements [`From`] for all integer and floating-point types.
 /// A conversion from an integer type of 32 bits or less always preserves the exact integer value.
-/// Conversions from 64-bit integers or more may round to the nearest representable integer.
-/// Conversion from floating-point types rounds to the nearest representable Dec64 value,
-/// using the Grisu2 algorithm which is perfectly accurate in over 99.4% of cases.
+/// Conversions from 64-bit integers or more may round to the nearest representable integer,
+/// while [`Dec64::from_i64_exact`] and friends only succeed if no rounding is necessary.
+/// Conversions back to integer types are implemented with [`TryFrom`], which fails instead of truncating or wrapping,
+/// and [`Dec64::to_i64_rounded`] and friends round to an integer first.
+/// Conversion from floating-point types results in the shortest decimal that converts back to the same float,
+/// or the nearest representable Dec64 value if that decimal has too many digits.
+/// Float NaN and infinities become [`NAN`], while [`Dec64::try_from_f64`] and [`Dec64::try_from_f32`] fail for them instead.
+/// Conversions back to [`f64`] and [`f32`] are correctly rounded, and [`Dec64::to_f64_exact`] only succeeds if no rounding is necessary.
+/// Decimal strings can be parsed exactly via [`FromStr`](core::str::FromStr), see [`str::parse`].
 ///
 /// Constructing a Dec64 directly can be done in three ways:
 /// - [`Dec64::new`] converts any exponent and coefficient combination into the closest representable Dec64.
@@ -97,104 +128,93 @@ impl Dec64 {
         Self(value)
     }
 
+    /// Returns the raw bitpattern, which can be turned back into the same Dec64 with [`Dec64::from_raw`].
+    #[inline]
+    pub const fn to_raw(self) -> i64 {
+        self.0
+    }
+
+    /// Returns the raw bitpattern as a byte array in big-endian (network) byte order.
+    ///
+    /// ```
+    /// # use dec64::consts::*;
+    /// assert_eq!(ONE.to_be_bytes(), [0, 0, 0, 0, 0, 0, 1, 0]);
+    /// ```
+    #[inline]
+    pub const fn to_be_bytes(self) -> [u8; 8] {
+        self.0.to_be_bytes()
+    }
+
+    /// Returns the raw bitpattern as a byte array in little-endian byte order.
+    ///
+    /// ```
+    /// # use dec64::consts::*;
+    /// assert_eq!(ONE.to_le_bytes(), [0, 1, 0, 0, 0, 0, 0, 0]);
+    /// ```
+    #[inline]
+    pub const fn to_le_bytes(self) -> [u8; 8] {
+        self.0.to_le_bytes()
+    }
+
+    /// Returns the raw bitpattern as a byte array in native byte order.
+    ///
+    /// Prefer [`Dec64::to_be_bytes`] or [`Dec64::to_le_bytes`] for data that leaves the machine.
+    #[inline]
+    pub const fn to_ne_bytes(self) -> [u8; 8] {
+        self.0.to_ne_bytes()
+    }
+
+    /// Constructs a Dec64 from its raw bitpattern in big-endian (network) byte order, like [`Dec64::from_raw`].
+    #[inline]
+    pub const fn from_be_bytes(bytes: [u8; 8]) -> Self {
+        Self(i64::from_be_bytes(bytes))
+    }
+
+    /// Constructs a Dec64 from its raw bitpattern in little-endian byte order, like [`Dec64::from_raw`].
+    #[inline]
+    pub const fn from_le_bytes(bytes: [u8; 8]) -> Self {
+        Self(i64::from_le_bytes(bytes))
+    }
+
+    /// Constructs a Dec64 from its raw bitpattern in native byte order, like [`Dec64::from_raw`].
+    #[inline]
+    pub const fn from_ne_bytes(bytes: [u8; 8]) -> Self {
+        Self(i64::from_ne_bytes(bytes))
+    }
+
     /// Construct a Dec64 from coefficient and exponent values.
     ///
     /// This will produce only standard (coefficient zeroed) kind of zero and NaN.
     ///
     /// Numbers that are too huge to be contained in this format become NaN.
     /// Numbers that are too tiny to be contained in this format become zero.
-    pub fn new(mut coefficient: i64, mut exponent: i32) -> Self {
+    /// Coefficients that are too long are rounded half away from zero.
+    pub fn new(coefficient: i64, exponent: i32) -> Self {
         if coefficient == 0 {
             // If the coefficient is zero, also zero the exponent.
             return ZERO;
         }
 
-        // Is the exponent within supported range?
-        if i32::from(MIN_EXP) <= exponent && exponent <= MAX_EXP.into() {
-            // Is the coefficient within supported range?
-            if (MIN_COEFFICIENT..=MAX_COEFFICIENT).contains(&coefficient) {
-                // Coefficient and exponent are OK.
-                return Dec64::from_parts(coefficient, exponent as i8);
-            } else {
-                // The coefficient is too long.
-                // Add one to the exponent and Divide the coefficient by 10.
-                loop {
-                    exponent += 1;
-                    if exponent > MAX_EXP.into() {
-                        // We cannot fit this number.
-                        return NAN;
-                    }
-
-                    let rem = coefficient % 10;
-                    coefficient /= 10;
-                    // Reminder of coefficient division for rounding decision.
-                    // Does it fit now?
-                    if (MIN_COEFFICIENT..=MAX_COEFFICIENT).contains(&coefficient) {
-                        // Examine the remainder to determine if the coefficient should be rounded up
-                        // or down. We will shift before adding in the rounding bit to get the cheap
-                        // overflow check. If rounding does not cause overflow, pack up and get out.
-                        let round_add = if rem <= -5 {
-                            -1 << 8
-                        } else if rem >= 5 {
-                            1 << 8
-                        } else {
-                            0
-                        };
-
-                        // If rounding caused the coefficient to overflow, then go one more time
-                        // through the loop. Otherwise return the dec64.
-                        // This is extremely unlikely.
-                        let (ret_value, overflow) = (coefficient << 8).overflowing_add(round_add);
-                        if !overflow {
-                            if coefficient == 0 {
-                                // If the coefficient is zero, also zero the exponent.
-                                return ZERO;
-                            }
-                            return Dec64(ret_value | ((exponent as u8) as i64));
-                        }
-                    }
-                }
-            }
-        } else if exponent > MAX_EXP.into() {
-            // The exponent is too big. We can attempt to reduce it by scaling back.
-            // This can decrease it in a small set of cases.
-            loop {
-                // try multiplying the coefficient by 10
-                let (coefficient_mul_10, overflow) = coefficient.overflowing_mul(10);
-                if overflow || !(MIN_COEFFICIENT..=MAX_COEFFICIENT).contains(&coefficient_mul_10) {
-                    // We failed to salvage.
-                    return NAN;
-                }
-                coefficient = coefficient_mul_10;
-
-                // decrement the exponent
-                exponent -= 1;
-                if exponent <= MAX_EXP.into() {
-                    return Dec64::from_parts(coefficient, exponent as i8);
-                }
-            }
-        } else if exponent < MIN_EXP.into() {
-            // The exponent is too small. We can attempt to increase it by scaling forward.
-            // This can increase it in a small set of cases.
-            loop {
-                let (coefficient_div_10, overflow) = coefficient.overflowing_div(10);
-                if overflow || coefficient_div_10 == 0 {
-                    // Value is too small to salvage.
-                    return ZERO;
-                }
-                coefficient = coefficient_div_10;
-
-                // increment the exponent
-                exponent += 1;
-                if exponent >= MIN_EXP.into() {
-                    return Dec64::from_parts(coefficient, exponent as i8);
-                }
-            }
+        // Are the coefficient and exponent within supported range?
+        if i32::from(MIN_EXP) <= exponent
+            && exponent <= i32::from(MAX_EXP)
+            && Self::coefficient_in_range(coefficient)
+        {
+            return Dec64::from_parts(coefficient, exponent as i8);
         }
 
-        // We should've accounted for all cases.
-        // If we ever reach here then there's a BUG in the implementation.
-        unreachable!("Dec64::pack(): BUG");
+        // The coefficient is too long or the exponent is out of range,
+        // so the number has to be scaled and rounded.
+        Wide::exact(coefficient.into(), exponent)
+            .round(RoundingMode::HalfUp)
+            .0
+    }
+
+    /// Construct a Dec64 from coefficient and exponent values, rounding with the given mode.
+    ///
+    /// Apart from the rounding mode, this behaves like [`Dec64::new`].
+    pub fn new_rounded(coefficient: i64, exponent: i32, mode: RoundingMode) -> Self {
+        Wide::exact(coefficient.into(), exponent).round(mode).0
     }
 
     /// Returns the DEC64 coefficient.
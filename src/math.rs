@@ -1,7 +1,5 @@
-use crate::consts::ZERO;
-
 /// Implementations of mathematical operations on DEC64.
-use super::{Dec64, POWERS_OF_10, SIGN_MASK, consts::NAN};
+use super::{Dec64, Flags, POWERS_OF_10, RoundingMode, SIGN_MASK, Wide, consts::NAN};
 
 impl Dec64 {
     /// Returns the sign of the DEC64 (-1, 0, 1).
@@ -144,43 +142,45 @@ impl Dec64 {
     /// Rounding to place 0 is the same as [`Self::round`], i.e. round to nearest integer.
     #[inline]
     pub fn round_to_places(self, places: i8) -> Self {
-        if self.is_nan() {
-            return NAN;
-        }
-        let new_exponent = self.exponent();
-        let effective_one_exponent = (places - self.exponent()).max(0);
-        // Obtain the number 1 scaled to the same exponent as the coefficient.
-        let (scaled_one, did_overflow) = 10i64.overflowing_pow(effective_one_exponent as u32);
+        self.round_to_places_with(places, RoundingMode::HalfUp)
+    }
 
-        // println!("eff 1 {effective_one_exponent} scaled {scaled_one}");
+    /// Round this DEC64 number to a certain number of decimal places with the given rounding mode.
+    /// See [`Self::round_to_places`] for the meaning of `places`.
+    #[inline]
+    pub fn round_to_places_with(self, places: i8, mode: RoundingMode) -> Self {
+        self.round_to_places_flagged(places, mode).0
+    }
 
-        if did_overflow {
-            // Calculating the scaled 1 overflowed.
-            // Since the coefficient is below 10^15, and the scaled 1 can go up to 10^19,
-            // we’re definitely small enough (below a thousandth) to round to 0.
-            return ZERO;
+    /// Rounding to decimal places that also returns the signalled conditions.
+    pub(crate) fn round_to_places_flagged(self, places: i8, mode: RoundingMode) -> (Self, Flags) {
+        if self.is_nan() {
+            return (NAN, Flags::empty());
         }
-
-        let remainder = self.coefficient().abs() % scaled_one;
-        if remainder == 0 {
-            // We’re already rounded, so the modulus method doesn’t work.
-            return self;
+        let count = places as i16 - self.exponent() as i16;
+        if count <= 0 {
+            // We’re already rounded.
+            return (self, Flags::empty());
         }
-
-        let scaled_half = scaled_one / 2;
-        let new_coefficient = if remainder < scaled_half {
-            // round down to next lower value
-            self.coefficient().abs() - remainder
+        let (rounded, exact) =
+            Wide::exact(self.coefficient().into(), 0).drop_digits(count as u32, mode);
+        let flags = if exact {
+            Flags::empty()
         } else {
-            // round up to next higher value
-            self.coefficient().abs() + (scaled_one - remainder)
+            Flags::INEXACT
         };
-        // println!(
-        //     "old coeff {} abs {} new coeff {new_coefficient} scaled one {scaled_one} => {}",
-        //     self.coefficient(),
-        //     self.coefficient().abs(),
-        //     new_coefficient * self.sign() as i64
-        // );
-        Self::new(new_coefficient * self.sign() as i64, new_exponent as i32)
+        if count > 18 {
+            // The scaled 1 doesn’t fit into 64 bits, but the coefficient is below 10^17,
+            // and rounding results in 0 or ±1 at the given place.
+            return (Self::new(rounded as i64, places.into()), flags);
+        }
+        // Keep the exponent by scaling the rounded coefficient back up.
+        // If that carries out of the coefficient range, the exponent is raised instead,
+        // which is exact since the lowest digit is zero.
+        let result = Self::new(
+            rounded as i64 * POWERS_OF_10[count as usize] as i64,
+            self.exponent().into(),
+        );
+        (result, flags)
     }
 }
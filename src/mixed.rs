@@ -0,0 +1,322 @@
+//! Operators and comparisons between DEC64 and primitive numbers.
+//!
+//! Integer operands of addition, subtraction and multiplication take a fast path
+//! if the DEC64 is an integer with exponent zero and the result fits the coefficient.
+//! Otherwise, and for floating-point operands, the primitive is converted with [`From`] first.
+
+use core::cmp::Ordering;
+use core::ops::{Add, Div, Mul, Sub};
+
+use crate::Dec64;
+
+impl Dec64 {
+    /// Returns the integer as a coefficient, if it is in range.
+    #[inline]
+    fn integer_coefficient<T: TryInto<i64>>(integer: T) -> Option<i64> {
+        integer
+            .try_into()
+            .ok()
+            .filter(|&coefficient| Self::coefficient_in_range(coefficient))
+    }
+
+    /// Applies an operation to the coefficient of a number with exponent zero and an integer coefficient,
+    /// if both exist and the result is in range.
+    #[inline]
+    fn integer_fast_path(
+        self,
+        integer: Option<i64>,
+        operation: impl FnOnce(i64, i64) -> Option<i64>,
+    ) -> Option<Dec64> {
+        if self.exponent() != 0 {
+            return None;
+        }
+        let result = operation(self.coefficient(), integer?)?;
+        Self::coefficient_in_range(result).then(|| Dec64::from_parts(result, 0))
+    }
+
+    /// Exactly compares this number to an integer given as sign and magnitude.
+    ///
+    /// Like in the [`Ord`] implementation, NaN is greater than all integers.
+    fn cmp_integer(self, negative: bool, magnitude: u128) -> Ordering {
+        if self.is_nan() {
+            return Ordering::Greater;
+        }
+        let coefficient = self.coefficient();
+        if coefficient == 0 || magnitude == 0 || (coefficient < 0) != negative {
+            // The signs alone determine the order.
+            let integer_sign = if magnitude == 0 {
+                0
+            } else if negative {
+                -1
+            } else {
+                1
+            };
+            return coefficient.signum().cmp(&integer_sign);
+        }
+
+        // Both numbers have the same sign, so compare the magnitudes at a common exponent.
+        // If scaling overflows, the scaled number is larger than any 128-bit number.
+        let absolute = coefficient.unsigned_abs() as u128;
+        let exponent = self.exponent();
+        let ordering = if exponent >= 0 {
+            match 10u128
+                .checked_pow(exponent as u32)
+                .and_then(|power| power.checked_mul(absolute))
+            {
+                Some(scaled) => scaled.cmp(&magnitude),
+                None => Ordering::Greater,
+            }
+        } else {
+            match 10u128
+                .checked_pow(exponent.unsigned_abs() as u32)
+                .and_then(|power| power.checked_mul(magnitude))
+            {
+                Some(scaled) => absolute.cmp(&scaled),
+                None => Ordering::Less,
+            }
+        };
+        if negative {
+            ordering.reverse()
+        } else {
+            ordering
+        }
+    }
+
+    /// Compares this number to a float. Infinities are smaller or larger than all numbers,
+    /// and float NaN is unordered.
+    fn partial_cmp_float(self, float: f64, converted: impl FnOnce() -> Dec64) -> Option<Ordering> {
+        if float.is_nan() {
+            None
+        } else if self.is_nan() {
+            Some(Ordering::Greater)
+        } else if float.is_infinite() {
+            Some(if float > 0.0 {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            })
+        } else {
+            self.partial_cmp(&converted())
+        }
+    }
+}
+
+macro_rules! impl_integer_mixed {
+    ($sign_magnitude:expr; $( $t:ty ),*) => ($(
+        impl Add<$t> for Dec64 {
+            type Output = Dec64;
+
+            #[inline]
+            fn add(self, rhs: $t) -> Dec64 {
+                self.integer_fast_path(Dec64::integer_coefficient(rhs), i64::checked_add)
+                    .unwrap_or_else(|| self + Dec64::from(rhs))
+            }
+        }
+
+        impl Add<Dec64> for $t {
+            type Output = Dec64;
+
+            #[inline]
+            fn add(self, rhs: Dec64) -> Dec64 {
+                rhs + self
+            }
+        }
+
+        impl Sub<$t> for Dec64 {
+            type Output = Dec64;
+
+            #[inline]
+            fn sub(self, rhs: $t) -> Dec64 {
+                self.integer_fast_path(Dec64::integer_coefficient(rhs), i64::checked_sub)
+                    .unwrap_or_else(|| self - Dec64::from(rhs))
+            }
+        }
+
+        impl Sub<Dec64> for $t {
+            type Output = Dec64;
+
+            #[inline]
+            fn sub(self, rhs: Dec64) -> Dec64 {
+                rhs.integer_fast_path(Dec64::integer_coefficient(self), |rhs, lhs| lhs.checked_sub(rhs))
+                    .unwrap_or_else(|| Dec64::from(self) - rhs)
+            }
+        }
+
+        impl Mul<$t> for Dec64 {
+            type Output = Dec64;
+
+            #[inline]
+            fn mul(self, rhs: $t) -> Dec64 {
+                self.integer_fast_path(Dec64::integer_coefficient(rhs), i64::checked_mul)
+                    .unwrap_or_else(|| self * Dec64::from(rhs))
+            }
+        }
+
+        impl Mul<Dec64> for $t {
+            type Output = Dec64;
+
+            #[inline]
+            fn mul(self, rhs: Dec64) -> Dec64 {
+                rhs * self
+            }
+        }
+
+        impl Div<$t> for Dec64 {
+            type Output = Dec64;
+
+            #[inline]
+            fn div(self, rhs: $t) -> Dec64 {
+                self / Dec64::from(rhs)
+            }
+        }
+
+        impl Div<Dec64> for $t {
+            type Output = Dec64;
+
+            #[inline]
+            fn div(self, rhs: Dec64) -> Dec64 {
+                Dec64::from(self) / rhs
+            }
+        }
+
+        impl PartialEq<$t> for Dec64 {
+            #[inline]
+            fn eq(&self, other: &$t) -> bool {
+                self.partial_cmp(other) == Some(Ordering::Equal)
+            }
+        }
+
+        impl PartialEq<Dec64> for $t {
+            #[inline]
+            fn eq(&self, other: &Dec64) -> bool {
+                other == self
+            }
+        }
+
+        impl PartialOrd<$t> for Dec64 {
+            #[inline]
+            // The cast is a no-op for 128-bit integers.
+            #[allow(clippy::unnecessary_cast)]
+            fn partial_cmp(&self, other: &$t) -> Option<Ordering> {
+                let (negative, magnitude) = $sign_magnitude(*other);
+                Some(self.cmp_integer(negative, magnitude))
+            }
+        }
+
+        impl PartialOrd<Dec64> for $t {
+            #[inline]
+            fn partial_cmp(&self, other: &Dec64) -> Option<Ordering> {
+                other.partial_cmp(self).map(Ordering::reverse)
+            }
+        }
+    )*)
+}
+
+macro_rules! impl_float_mixed {
+    ($( $t:ty ),*) => ($(
+        impl Add<$t> for Dec64 {
+            type Output = Dec64;
+
+            #[inline]
+            fn add(self, rhs: $t) -> Dec64 {
+                self + Dec64::from(rhs)
+            }
+        }
+
+        impl Add<Dec64> for $t {
+            type Output = Dec64;
+
+            #[inline]
+            fn add(self, rhs: Dec64) -> Dec64 {
+                Dec64::from(self) + rhs
+            }
+        }
+
+        impl Sub<$t> for Dec64 {
+            type Output = Dec64;
+
+            #[inline]
+            fn sub(self, rhs: $t) -> Dec64 {
+                self - Dec64::from(rhs)
+            }
+        }
+
+        impl Sub<Dec64> for $t {
+            type Output = Dec64;
+
+            #[inline]
+            fn sub(self, rhs: Dec64) -> Dec64 {
+                Dec64::from(self) - rhs
+            }
+        }
+
+        impl Mul<$t> for Dec64 {
+            type Output = Dec64;
+
+            #[inline]
+            fn mul(self, rhs: $t) -> Dec64 {
+                self * Dec64::from(rhs)
+            }
+        }
+
+        impl Mul<Dec64> for $t {
+            type Output = Dec64;
+
+            #[inline]
+            fn mul(self, rhs: Dec64) -> Dec64 {
+                Dec64::from(self) * rhs
+            }
+        }
+
+        impl Div<$t> for Dec64 {
+            type Output = Dec64;
+
+            #[inline]
+            fn div(self, rhs: $t) -> Dec64 {
+                self / Dec64::from(rhs)
+            }
+        }
+
+        impl Div<Dec64> for $t {
+            type Output = Dec64;
+
+            #[inline]
+            fn div(self, rhs: Dec64) -> Dec64 {
+                Dec64::from(self) / rhs
+            }
+        }
+
+        /// Compares to the float converted with [`From`], so that for example `0.1` is equal to one tenth.
+        impl PartialEq<$t> for Dec64 {
+            #[inline]
+            fn eq(&self, other: &$t) -> bool {
+                self.partial_cmp(other) == Some(Ordering::Equal)
+            }
+        }
+
+        impl PartialEq<Dec64> for $t {
+            #[inline]
+            fn eq(&self, other: &Dec64) -> bool {
+                other == self
+            }
+        }
+
+        impl PartialOrd<$t> for Dec64 {
+            #[inline]
+            fn partial_cmp(&self, other: &$t) -> Option<Ordering> {
+                self.partial_cmp_float(*other as f64, || Dec64::from(*other))
+            }
+        }
+
+        impl PartialOrd<Dec64> for $t {
+            #[inline]
+            fn partial_cmp(&self, other: &Dec64) -> Option<Ordering> {
+                other.partial_cmp(self).map(Ordering::reverse)
+            }
+        }
+    )*)
+}
+
+impl_integer_mixed!(|integer| (integer < 0, (integer as i128).unsigned_abs()); i8, i16, i32, i64, i128, isize);
+impl_integer_mixed!(|integer| (false, integer as u128); u8, u16, u32, u64, u128, usize);
+impl_float_mixed!(f32, f64);
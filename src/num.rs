@@ -0,0 +1,236 @@
+//! [`num_traits`] implementations, enabled by the `num-traits` feature.
+//!
+//! The traits follow the semantics of the floating-point implementations where DEC64 behaves like a float,
+//! e.g. conversions from primitives round instead of failing, and conversions to integers truncate.
+
+use num_traits::{
+    Bounded, CheckedAdd, CheckedDiv, CheckedMul, CheckedNeg, CheckedRem, CheckedSub, FromPrimitive,
+    Num, NumCast, One, Pow, SaturatingAdd, SaturatingMul, SaturatingSub, Signed, ToPrimitive, Zero,
+};
+
+use crate::{Dec64, MAX, MIN, NAN, NEGATIVE_ONE, ONE, ParseDec64Error, RoundingMode, ZERO};
+
+impl Zero for Dec64 {
+    #[inline]
+    fn zero() -> Self {
+        ZERO
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        Dec64::is_zero(*self)
+    }
+}
+
+impl One for Dec64 {
+    #[inline]
+    fn one() -> Self {
+        ONE
+    }
+}
+
+impl Num for Dec64 {
+    type FromStrRadixErr = ParseDec64Error;
+
+    /// Parses a decimal string like [`str::parse`]. Only radix 10 is supported.
+    fn from_str_radix(text: &str, radix: u32) -> Result<Self, ParseDec64Error> {
+        if radix != 10 {
+            return Err(ParseDec64Error::Invalid);
+        }
+        text.parse()
+    }
+}
+
+impl Signed for Dec64 {
+    #[inline]
+    fn abs(&self) -> Self {
+        Dec64::abs(*self)
+    }
+
+    /// The positive difference, or zero if `self` is not larger than `other`.
+    fn abs_sub(&self, other: &Self) -> Self {
+        if self.is_nan() || other.is_nan() {
+            NAN
+        } else if self <= other {
+            ZERO
+        } else {
+            *self - *other
+        }
+    }
+
+    /// One, zero or negative one depending on the sign of the number, or NaN for NaN.
+    fn signum(&self) -> Self {
+        if self.is_nan() {
+            return NAN;
+        }
+        match self.sign() {
+            1 => ONE,
+            -1 => NEGATIVE_ONE,
+            _ => ZERO,
+        }
+    }
+
+    #[inline]
+    fn is_positive(&self) -> bool {
+        !self.is_nan() && self.sign() > 0
+    }
+
+    #[inline]
+    fn is_negative(&self) -> bool {
+        !self.is_nan() && self.sign() < 0
+    }
+}
+
+impl Bounded for Dec64 {
+    #[inline]
+    fn min_value() -> Self {
+        MIN
+    }
+
+    #[inline]
+    fn max_value() -> Self {
+        MAX
+    }
+}
+
+impl FromPrimitive for Dec64 {
+    #[inline]
+    fn from_i64(number: i64) -> Option<Self> {
+        Some(number.into())
+    }
+
+    #[inline]
+    fn from_u64(number: u64) -> Option<Self> {
+        Some(number.into())
+    }
+
+    #[inline]
+    fn from_i128(number: i128) -> Option<Self> {
+        Some(number.into())
+    }
+
+    #[inline]
+    fn from_u128(number: u128) -> Option<Self> {
+        Some(number.into())
+    }
+
+    /// Fails for NaN, infinities and numbers beyond the range of DEC64, see [`Dec64::try_from_f32`].
+    #[inline]
+    fn from_f32(number: f32) -> Option<Self> {
+        Dec64::try_from_f32(number).ok()
+    }
+
+    /// Fails for NaN, infinities and numbers beyond the range of DEC64, see [`Dec64::try_from_f64`].
+    #[inline]
+    fn from_f64(number: f64) -> Option<Self> {
+        Dec64::try_from_f64(number).ok()
+    }
+}
+
+/// Conversions to integers truncate the fractional part, and fail for NaN and numbers out of range.
+impl ToPrimitive for Dec64 {
+    #[inline]
+    fn to_i64(&self) -> Option<i64> {
+        self.to_i64_rounded(RoundingMode::TowardZero).ok()
+    }
+
+    #[inline]
+    fn to_u64(&self) -> Option<u64> {
+        self.to_u64_rounded(RoundingMode::TowardZero).ok()
+    }
+
+    #[inline]
+    fn to_i128(&self) -> Option<i128> {
+        self.to_i128_rounded(RoundingMode::TowardZero).ok()
+    }
+
+    #[inline]
+    fn to_u128(&self) -> Option<u128> {
+        self.to_u128_rounded(RoundingMode::TowardZero).ok()
+    }
+
+    #[inline]
+    fn to_f32(&self) -> Option<f32> {
+        Some((*self).into())
+    }
+
+    #[inline]
+    fn to_f64(&self) -> Option<f64> {
+        Some((*self).into())
+    }
+}
+
+impl NumCast for Dec64 {
+    /// Converts integral values like integers with [`From`], and everything else via [`f64`] like [`Dec64::try_from_f64`].
+    ///
+    /// Large integral floats are therefore rounded from their exact value, which may differ from their shortest decimal.
+    fn from<T: ToPrimitive>(number: T) -> Option<Self> {
+        let float = number.to_f64()?;
+        // A truncating integer conversion is only exact if it matches the float.
+        if let Some(integer) = number.to_i128().filter(|&integer| integer as f64 == float) {
+            return Some(integer.into());
+        }
+        if let Some(integer) = number.to_u128().filter(|&integer| integer as f64 == float) {
+            return Some(integer.into());
+        }
+        Dec64::try_from_f64(float).ok()
+    }
+}
+
+macro_rules! impl_checked {
+    ($( $trait:ident, $method:ident );*) => ($(
+        impl $trait for Dec64 {
+            #[inline]
+            fn $method(&self, other: &Self) -> Option<Self> {
+                Dec64::$method(*self, *other)
+            }
+        }
+    )*)
+}
+
+impl_checked!(
+    CheckedAdd, checked_add; CheckedSub, checked_sub; CheckedMul, checked_mul;
+    CheckedDiv, checked_div; CheckedRem, checked_rem
+);
+
+impl CheckedNeg for Dec64 {
+    #[inline]
+    fn checked_neg(&self) -> Option<Self> {
+        Dec64::checked_neg(*self)
+    }
+}
+
+macro_rules! impl_saturating {
+    ($( $trait:ident, $method:ident );*) => ($(
+        impl $trait for Dec64 {
+            #[inline]
+            fn $method(&self, other: &Self) -> Self {
+                Dec64::$method(*self, *other)
+            }
+        }
+    )*)
+}
+
+impl_saturating!(
+    SaturatingAdd, saturating_add; SaturatingSub, saturating_sub; SaturatingMul, saturating_mul
+);
+
+impl Pow<i32> for Dec64 {
+    type Output = Dec64;
+
+    /// See [`Dec64::powi`].
+    #[inline]
+    fn pow(self, exponent: i32) -> Dec64 {
+        self.powi(exponent)
+    }
+}
+
+impl Pow<Dec64> for Dec64 {
+    type Output = Dec64;
+
+    /// See [`Dec64::powf`].
+    #[inline]
+    fn pow(self, exponent: Dec64) -> Dec64 {
+        self.powf(exponent)
+    }
+}
@@ -1,8 +1,11 @@
 //! Implementation of traits from `core::ops`.
 
-use core::ops::{Add, Div, Mul, Neg, Sub};
+use core::iter::{Product, Sum};
+use core::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
 
-use super::{COEFFICIENT_MASK, Dec64, MAX_EXP, MIN_EXP, NAN, ZERO};
+use super::{COEFFICIENT_MASK, Dec64, Flags, NAN, ONE, RoundingMode, Wide, ZERO};
 
 impl Add for Dec64 {
     type Output = Dec64;
@@ -20,98 +23,170 @@ impl Add for Dec64 {
             if !overflow {
                 return Dec64::from_raw(sum);
             }
-        } else {
+        } else if self.exponent() == other.exponent() {
             // The slow path is taken if the two operands do not both have zero exponents.
-            if self.exponent() == other.exponent() {
-                // The exponents match so we may add now. Zero out the exponents so there
-                // will be no carry into the coefficients when the coefficients are added.
-                // If the result is zero, then return the normal zero.
-                let (sum, overflow) =
-                    (self.0 & COEFFICIENT_MASK).overflowing_add(other.0 & COEFFICIENT_MASK);
-                if !overflow {
-                    return Dec64::from_parts(sum >> 8, self.exponent());
-                }
-            } else {
-                // The slower path is taken when neither operand is nan, and their
-                // exponents are different.
-
-                // Before addition can take place, the exponents
-                // must be made to match.
-                let (hi, lo) = if self.exponent() > other.exponent() {
-                    (self, other)
-                } else {
-                    (other, self)
-                };
-
-                let mut lo_coefficient = lo.coefficient();
-                // If lower value has zero coefficient return the higher.
-                if lo_coefficient == 0 {
-                    return hi;
-                }
-                let mut lo_exponent = lo.exponent();
-                let mut hi_coefficient = hi.coefficient();
-                let mut hi_exponent = hi.exponent();
-
-                // First we will try to decrease the high exponent. When we decrease the exponent
-                // by 1, we must also multiply the coefficient by 10. We can do this as long as
-                // there is no overflow. We have 8 extra bits to work with, so we can do this
-                // at least twice, possibly more.
-                loop {
-                    // Before decrementing the exponent, multiply.
-                    let (hi_coefficient_mul_10, overflow) = hi_coefficient.overflowing_mul(10);
-                    if overflow {
-                        // We cannot decrease exponent any more.
-                        break;
-                    }
-
-                    hi_exponent -= 1;
-                    hi_coefficient = hi_coefficient_mul_10;
-
-                    // Are the exponents equal yet?
-                    if hi_exponent == lo_exponent {
-                        // We can sum & then pack.
-                        let sum = hi_coefficient + lo_coefficient;
-                        return Self::new(sum, hi_exponent as i32);
-                    }
-                }
-
-                // If we're still not done yet and we cannot decrease the first exponent any more,
-                // so we must instead try to increase the second exponent, which will result in
-                // a loss of significance.
-                // That is the heartbreak of floating point.
-                loop {
-                    lo_coefficient /= 10;
-                    lo_exponent += 1;
-
-                    // Are the exponents equal yet?
-                    if hi_exponent == lo_exponent {
-                        // We can sum & then pack.
-                        let sum = hi_coefficient + lo_coefficient;
-                        return Self::new(sum, lo_exponent as i32);
-                    }
-                }
+            // The exponents match so we may add now. Zero out the exponents so there
+            // will be no carry into the coefficients when the coefficients are added.
+            // If the result is zero, then return the normal zero.
+            let (sum, overflow) =
+                (self.0 & COEFFICIENT_MASK).overflowing_add(other.0 & COEFFICIENT_MASK);
+            if !overflow {
+                return Dec64::from_parts(sum >> 8, self.exponent());
             }
         }
 
-        // Sum had an overflow.
-        // This path happens only when both exponents are the same.
-        // Re-add shifted coefficients (this won't overflow) and pack.
-        // In original implementation of this path is much more elegant,
-        // But here we don't have access to the carry flag.
-        let sum = self.coefficient() + other.coefficient();
-
-        Self::new(sum, self.exponent() as i32)
+        Self::add_coefficients(
+            self.coefficient(),
+            self.exponent(),
+            other.coefficient(),
+            other.exponent(),
+        )
     }
 }
 
-/// Currently this doesn't have specialized implementation.
-/// Just add negated number.
 impl Sub for Dec64 {
     type Output = Dec64;
 
-    #[inline]
     fn sub(self, other: Dec64) -> Dec64 {
-        self.add(-other)
+        if self.is_nan() || other.is_nan() {
+            return NAN;
+        }
+
+        // The same fast paths as for addition apply.
+        if self.exponent() == 0 && other.exponent() == 0 {
+            let (difference, overflow) = self.0.overflowing_sub(other.0);
+            if !overflow {
+                return Dec64::from_raw(difference);
+            }
+        } else if self.exponent() == other.exponent() {
+            let (difference, overflow) =
+                (self.0 & COEFFICIENT_MASK).overflowing_sub(other.0 & COEFFICIENT_MASK);
+            if !overflow {
+                return Dec64::from_parts(difference >> 8, self.exponent());
+            }
+        }
+
+        // Negating the coefficient cannot overflow in 64 bits,
+        // even though it can overflow the 56-bit DEC64 coefficient.
+        Self::add_coefficients(
+            self.coefficient(),
+            self.exponent(),
+            -other.coefficient(),
+            other.exponent(),
+        )
+    }
+}
+
+impl Dec64 {
+    /// Addition, rounding with the given mode.
+    ///
+    /// Apart from the rounding mode, this behaves like the `+` operator.
+    #[inline]
+    pub fn add_rounded(self, other: Dec64, mode: RoundingMode) -> Dec64 {
+        self.add_flagged(other, mode).0
+    }
+
+    /// Subtraction, rounding with the given mode.
+    ///
+    /// Apart from the rounding mode, this behaves like the `-` operator.
+    #[inline]
+    pub fn sub_rounded(self, other: Dec64, mode: RoundingMode) -> Dec64 {
+        self.sub_flagged(other, mode).0
+    }
+
+    /// Multiplication, rounding with the given mode.
+    ///
+    /// Apart from the rounding mode, this behaves like the `*` operator.
+    #[inline]
+    pub fn mul_rounded(self, other: Dec64, mode: RoundingMode) -> Dec64 {
+        self.mul_flagged(other, mode).0
+    }
+
+    /// Division, rounding with the given mode.
+    ///
+    /// Apart from the rounding mode, this behaves like the `/` operator.
+    #[inline]
+    pub fn div_rounded(self, other: Dec64, mode: RoundingMode) -> Dec64 {
+        self.div_flagged(other, mode).0
+    }
+
+    /// Addition that also returns the signalled conditions.
+    pub(crate) fn add_flagged(self, other: Dec64, mode: RoundingMode) -> (Dec64, Flags) {
+        if self.is_nan() || other.is_nan() {
+            return (NAN, Flags::empty());
+        }
+        Wide::sum(
+            self.coefficient(),
+            self.exponent(),
+            other.coefficient(),
+            other.exponent(),
+        )
+        .round(mode)
+    }
+
+    /// Subtraction that also returns the signalled conditions.
+    pub(crate) fn sub_flagged(self, other: Dec64, mode: RoundingMode) -> (Dec64, Flags) {
+        if self.is_nan() || other.is_nan() {
+            return (NAN, Flags::empty());
+        }
+        Wide::sum(
+            self.coefficient(),
+            self.exponent(),
+            -other.coefficient(),
+            other.exponent(),
+        )
+        .round(mode)
+    }
+
+    /// Multiplication that also returns the signalled conditions.
+    pub(crate) fn mul_flagged(self, other: Dec64, mode: RoundingMode) -> (Dec64, Flags) {
+        if self.is_nan() || other.is_nan() {
+            return (NAN, Flags::empty());
+        }
+        if self.is_zero() || other.is_zero() {
+            return (ZERO, Flags::empty());
+        }
+
+        // (c1 * 10^e1) * (c2 * 10^e2) = c1 * c2 * 10^(e1 + e2)
+        // Do multiplication in 128 bits and reduce coefficient later.
+        Wide::product(self, other).round(mode)
+    }
+
+    /// Division that also returns the signalled conditions.
+    pub(crate) fn div_flagged(self, other: Dec64, mode: RoundingMode) -> (Dec64, Flags) {
+        if self.is_nan() || other.is_nan() {
+            return (NAN, Flags::empty());
+        }
+        if other.is_zero() {
+            let flags = if self.is_zero() {
+                Flags::INVALID
+            } else {
+                Flags::DIVISION_BY_ZERO
+            };
+            return (NAN, flags);
+        }
+        if self.is_zero() {
+            return (ZERO, Flags::empty());
+        }
+
+        // (c1 * 10^e1) * (c2 * 10^e2)^-1 = (c1 * c2^-1) * 10^(e1-e2)
+        Wide::quotient(self, other).round(mode)
+    }
+
+    /// Adds two numbers given as coefficient and exponent.
+    ///
+    /// This is the slow path of addition and subtraction, which is taken
+    /// if the exponents differ or the coefficients overflowed.
+    /// The coefficients may be slightly larger than the DEC64 coefficient range.
+    fn add_coefficients(
+        lhs_coefficient: i64,
+        lhs_exponent: i8,
+        rhs_coefficient: i64,
+        rhs_exponent: i8,
+    ) -> Dec64 {
+        Wide::sum(lhs_coefficient, lhs_exponent, rhs_coefficient, rhs_exponent)
+            .round(RoundingMode::HalfUp)
+            .0
     }
 }
 
@@ -144,110 +219,117 @@ impl Neg for Dec64 {
 impl Mul for Dec64 {
     type Output = Dec64;
 
+    #[inline]
     fn mul(self, rhs: Self) -> Self::Output {
-        if self.is_nan() || rhs.is_nan() {
-            return NAN;
-        }
-        if self.is_zero() || rhs.is_zero() {
-            return ZERO;
-        }
-
-        // (c1 * 10^e1) * (c2 * 10^e2) = c1 * c2 * 10^(e1 + e2)
-
-        // Do multiplication in 128 bits and reduce coefficient later.
-        let mut full_coefficient = self.coefficient() as i128 * rhs.coefficient() as i128;
-        // last bit that was flushed out during coefficient reduction
-        let mut last_flushed_bit = 0;
-        let mut new_exponent = self.exponent() as i16 + rhs.exponent() as i16;
-        // println!("-- {full_coefficient} * 10^{new_exponent}");
-        // Reduce coefficient as needed (possibly reducing precision):
-        // either coefficient is out of range, or exponent is too small.
-        while !Self::coefficient_in_range(full_coefficient) || new_exponent < MIN_EXP.into() {
-            last_flushed_bit = full_coefficient.abs() % 10;
-            full_coefficient /= 10;
-            new_exponent += 1;
-            // println!("reduced out-of-range coeff {full_coefficient} at exponent {new_exponent}");
-        }
-
-        // Reduce exponent as needed while increasing the coefficient (but not past what we did above)
-        while new_exponent > MAX_EXP.into() && Self::coefficient_in_range(full_coefficient) {
-            last_flushed_bit = 0;
-            full_coefficient *= 10;
-            new_exponent -= 1;
-            // println!("increased coeff {full_coefficient} for exponent {new_exponent}");
-        }
-
-        // Number is out of range (still), so return nan (if too large) or zero (if too small)
-        if new_exponent > MAX_EXP.into() || !Self::coefficient_in_range(full_coefficient) {
-            NAN
-        } else if new_exponent < MIN_EXP.into() {
-            ZERO
-        } else {
-            // Add 1 to coefficient
-            Self::from_parts(
-                (full_coefficient
-                    + if last_flushed_bit >= 5 {
-                        full_coefficient.signum()
-                    } else {
-                        0
-                    }) as _,
-                new_exponent as _,
-            )
-        }
+        self.mul_rounded(rhs, RoundingMode::HalfUp)
     }
 }
 
 impl Div for Dec64 {
     type Output = Dec64;
 
+    #[inline]
     fn div(self, rhs: Self) -> Self::Output {
-        if self.is_nan() || rhs.is_nan() || rhs.is_zero() {
-            return NAN;
+        self.div_rounded(rhs, RoundingMode::HalfUp)
+    }
+}
+
+impl Rem for Dec64 {
+    type Output = Dec64;
+
+    /// The remainder of truncating division, which has the same sign as `self`. See [`Dec64::div_rem`].
+    #[inline]
+    fn rem(self, rhs: Self) -> Self::Output {
+        self.div_rem(rhs).1
+    }
+}
+
+/// Implements a binary operator for borrowed operands and its compound assignment operator,
+/// all in terms of the operator on owned operands.
+macro_rules! forward_binop {
+    ($( $imp:ident::$method:ident, $assign_imp:ident::$assign_method:ident );*) => ($(
+        impl $imp<&Dec64> for Dec64 {
+            type Output = Dec64;
+
+            #[inline]
+            fn $method(self, rhs: &Dec64) -> Dec64 {
+                $imp::$method(self, *rhs)
+            }
         }
-        if self.is_zero() {
-            return ZERO;
+
+        impl $imp<Dec64> for &Dec64 {
+            type Output = Dec64;
+
+            #[inline]
+            fn $method(self, rhs: Dec64) -> Dec64 {
+                $imp::$method(*self, rhs)
+            }
         }
 
-        // (c1 * 10^e1) * (c2 * 10^e2)^-1 = (c1 * c2^-1) * 10^(e1-e2)
-        let mut lhs_coefficient = self.coefficient() as i128;
-        let rhs_coefficient = rhs.coefficient() as i128;
-        let mut maybe_coefficient = lhs_coefficient / rhs_coefficient;
-        let mut coefficient_remainder = lhs_coefficient % rhs_coefficient;
-        let mut new_exponent = self.exponent() as i16 - rhs.exponent() as i16;
-
-        // println!("coeff {maybe_coefficient} rem {coefficient_remainder}");
-
-        // Slow path: Division is inexact, increase coefficient sizes either until limit is reached or until division becomes exact.
-        while coefficient_remainder != 0 {
-            let (new_lhs, overflow) = lhs_coefficient.overflowing_mul(10);
-            // Reached coefficient precision limit, stop here.
-            if !Self::coefficient_in_range(maybe_coefficient) || overflow {
-                let last_flushed_bit = maybe_coefficient.abs() % 10;
-                new_exponent += 1;
-                maybe_coefficient = (maybe_coefficient / 10)
-                    + if last_flushed_bit >= 5 {
-                        maybe_coefficient.signum()
-                    } else {
-                        0
-                    };
-                // println!(
-                //     "stopping since out of range: lhs {lhs_coefficient} rem {coefficient_remainder} flushbit {last_flushed_bit}"
-                // );
-                break;
+        impl $imp<&Dec64> for &Dec64 {
+            type Output = Dec64;
+
+            #[inline]
+            fn $method(self, rhs: &Dec64) -> Dec64 {
+                $imp::$method(*self, *rhs)
+            }
+        }
+
+        impl $assign_imp for Dec64 {
+            #[inline]
+            fn $assign_method(&mut self, rhs: Dec64) {
+                *self = $imp::$method(*self, rhs);
+            }
+        }
+
+        impl $assign_imp<&Dec64> for Dec64 {
+            #[inline]
+            fn $assign_method(&mut self, rhs: &Dec64) {
+                *self = $imp::$method(*self, *rhs);
             }
-            lhs_coefficient = new_lhs;
-            new_exponent -= 1;
-            maybe_coefficient = lhs_coefficient / rhs_coefficient;
-            coefficient_remainder = lhs_coefficient % rhs_coefficient;
-            // println!("increased precision: coeff {maybe_coefficient} rem {coefficient_remainder}");
-        }
-        // Exponent is out of range, so return nan (if too large) or zero (if too small)
-        if new_exponent > MAX_EXP.into() {
-            NAN
-        } else if new_exponent < MIN_EXP.into() {
-            ZERO
-        } else {
-            Self::from_parts(maybe_coefficient as i64, new_exponent as i8)
         }
+    )*)
+}
+
+forward_binop!(
+    Add::add, AddAssign::add_assign;
+    Sub::sub, SubAssign::sub_assign;
+    Mul::mul, MulAssign::mul_assign;
+    Div::div, DivAssign::div_assign;
+    Rem::rem, RemAssign::rem_assign
+);
+
+impl Neg for &Dec64 {
+    type Output = Dec64;
+
+    #[inline]
+    fn neg(self) -> Dec64 {
+        -*self
+    }
+}
+
+/// Adds up all numbers, rounding after each addition. The sum of no numbers is zero.
+impl Sum for Dec64 {
+    fn sum<I: Iterator<Item = Dec64>>(iter: I) -> Dec64 {
+        iter.fold(ZERO, Add::add)
+    }
+}
+
+impl<'a> Sum<&'a Dec64> for Dec64 {
+    fn sum<I: Iterator<Item = &'a Dec64>>(iter: I) -> Dec64 {
+        iter.fold(ZERO, Add::add)
+    }
+}
+
+/// Multiplies all numbers, rounding after each multiplication. The product of no numbers is one.
+impl Product for Dec64 {
+    fn product<I: Iterator<Item = Dec64>>(iter: I) -> Dec64 {
+        iter.fold(ONE, Mul::mul)
+    }
+}
+
+impl<'a> Product<&'a Dec64> for Dec64 {
+    fn product<I: Iterator<Item = &'a Dec64>>(iter: I) -> Dec64 {
+        iter.fold(ONE, Mul::mul)
     }
 }
@@ -0,0 +1,158 @@
+//! From-String ([`FromStr`]) implementation for DEC64.
+
+use core::fmt::{self, Display, Formatter};
+use core::str::FromStr;
+
+use crate::{Dec64, NAN};
+
+/// Number of significant digits that are accumulated before further digits are dropped.
+///
+/// 18 digits always fit an [`i64`], and since [`Dec64::new`] rounds half away from zero,
+/// only the most significant dropped digit is ever relevant for rounding.
+/// Any digits beyond the 18th can therefore be ignored without changing the result.
+const MAX_DIGITS: u32 = 18;
+
+/// Maximum magnitude of the parsed exponent.
+/// Anything larger is already far out of range for DEC64, so we clamp to avoid overflow.
+const EXPONENT_LIMIT: i32 = 100_000;
+
+/// An error which can be returned when parsing a [`Dec64`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseDec64Error {
+    /// The string was empty.
+    Empty,
+    /// The string did not follow the DEC64 number grammar.
+    Invalid,
+}
+
+impl Display for ParseDec64Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("cannot parse DEC64 from empty string"),
+            Self::Invalid => f.write_str("invalid DEC64 literal"),
+        }
+    }
+}
+
+impl core::error::Error for ParseDec64Error {}
+
+/// Parses a DEC64 from a decimal string.
+///
+/// The accepted grammar is
+///
+/// ```text
+/// number   = "nan" | [sign] digits [exponent]
+/// digits   = digit+ ["." digit*] | "." digit+
+/// exponent = ("e" | "E") [sign] digit+
+/// sign     = "+" | "-"
+/// ```
+///
+/// where `nan` is matched case-insensitively.
+/// This accepts every string produced by the [`Display`] implementation.
+///
+/// Coefficients with more digits than DEC64 can hold are rounded the same way as [`Dec64::new`] does.
+/// Numbers that are too large become NaN, and numbers that are too small become zero.
+impl FromStr for Dec64 {
+    type Err = ParseDec64Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(ParseDec64Error::Empty);
+        }
+        if s.eq_ignore_ascii_case("nan") {
+            return Ok(NAN);
+        }
+
+        let bytes = s.as_bytes();
+        let mut index = 0;
+
+        let negative = match bytes[0] {
+            b'-' => {
+                index += 1;
+                true
+            }
+            b'+' => {
+                index += 1;
+                false
+            }
+            _ => false,
+        };
+
+        let mut coefficient: i64 = 0;
+        let mut significant_digits = 0;
+        let mut any_digits = false;
+        // Adjustment to the exponent caused by fraction digits and dropped integer digits.
+        let mut exponent: i32 = 0;
+        let mut seen_point = false;
+
+        while let Some(&byte) = bytes.get(index) {
+            match byte {
+                b'0'..=b'9' => {
+                    any_digits = true;
+                    let digit = (byte - b'0') as i64;
+                    if significant_digits < MAX_DIGITS {
+                        coefficient = coefficient * 10 + digit;
+                        if coefficient != 0 {
+                            significant_digits += 1;
+                        }
+                        if seen_point {
+                            exponent -= 1;
+                        }
+                    } else if !seen_point {
+                        // Dropped integer digit; still scales the number.
+                        exponent += 1;
+                    }
+                }
+                b'.' if !seen_point => seen_point = true,
+                _ => break,
+            }
+            index += 1;
+        }
+
+        if !any_digits {
+            return Err(ParseDec64Error::Invalid);
+        }
+
+        if let Some(&(b'e' | b'E')) = bytes.get(index) {
+            index += 1;
+            let exponent_negative = match bytes.get(index) {
+                Some(b'-') => {
+                    index += 1;
+                    true
+                }
+                Some(b'+') => {
+                    index += 1;
+                    false
+                }
+                _ => false,
+            };
+
+            let mut explicit_exponent: i32 = 0;
+            let mut any_exponent_digits = false;
+            while let Some(&byte @ b'0'..=b'9') = bytes.get(index) {
+                any_exponent_digits = true;
+                explicit_exponent =
+                    (explicit_exponent * 10 + (byte - b'0') as i32).min(EXPONENT_LIMIT);
+                index += 1;
+            }
+            if !any_exponent_digits {
+                return Err(ParseDec64Error::Invalid);
+            }
+
+            if exponent_negative {
+                exponent -= explicit_exponent;
+            } else {
+                exponent += explicit_exponent;
+            }
+        }
+
+        if index != bytes.len() {
+            return Err(ParseDec64Error::Invalid);
+        }
+
+        if negative {
+            coefficient = -coefficient;
+        }
+        Ok(Dec64::new(coefficient, exponent))
+    }
+}
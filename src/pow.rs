@@ -0,0 +1,127 @@
+//! Powers of DEC64.
+//!
+//! Integer powers are computed by repeated squaring with 38-digit intermediate results, and rounded once.
+//! They are exact whenever the exact power fits into 38 digits, which covers most compound interest over
+//! a few periods; otherwise, the lost digits are far below the last digit of the result.
+
+use crate::extended::Extended;
+use crate::{Dec64, MAX_COEFFICIENT, MAX_EXP, MIN_EXP, NAN, ONE, POWERS_OF_10, TWO, ZERO};
+
+impl Dec64 {
+    /// Returns `10^exponent` exactly, built directly from the exponent field.
+    ///
+    /// Returns zero if the power is too small for a DEC64 and NaN if it is too large.
+    ///
+    /// ```
+    /// # use dec64::{Dec64, consts::*};
+    /// assert_eq!(Dec64::pow10(-2), CENT);
+    /// assert_eq!(Dec64::pow10(100), GOOGOL);
+    /// assert_eq!(Dec64::pow10(143), Dec64::from_parts(10_000_000_000_000_000, 127));
+    /// ```
+    pub const fn pow10(exponent: i32) -> Dec64 {
+        if exponent < MIN_EXP as i32 {
+            ZERO
+        } else if exponent <= MAX_EXP as i32 {
+            Dec64::from_parts(1, exponent as i8)
+        } else {
+            // Move the excess of the exponent into the coefficient.
+            let excess = (exponent - MAX_EXP as i32) as usize;
+            if excess < POWERS_OF_10.len() && POWERS_OF_10[excess] <= MAX_COEFFICIENT as u64 {
+                Dec64::from_parts(POWERS_OF_10[excess] as i64, MAX_EXP)
+            } else {
+                NAN
+            }
+        }
+    }
+
+    /// Raises the number to an integer power, correctly rounded if the exact power fits into 38 digits.
+    ///
+    /// Zero to the power of zero is one, and zero to a negative power is NaN, like a division by zero.
+    /// Returns NaN for NaN and if the result is too large, and zero if it is too small.
+    ///
+    /// ```
+    /// # use dec64::{Dec64, consts::*};
+    /// // Compound interest: 1000 at 5 % for 10 years.
+    /// let rate = Dec64::from_parts(5, -2);
+    /// let amount = Dec64::from(1000) * (ONE + rate).powi(10);
+    /// assert_eq!(amount.round_to_places(-2), Dec64::from_parts(162889, -2));
+    /// assert_eq!(TWO.powi(-3), Dec64::from_parts(125, -3));
+    /// ```
+    pub fn powi(self, exponent: i32) -> Dec64 {
+        if self.is_nan() {
+            return NAN;
+        }
+        if exponent == 0 {
+            return ONE;
+        }
+        if self.is_zero() {
+            return if exponent > 0 { ZERO } else { NAN };
+        }
+
+        // Square and multiply from the lowest bit of the exponent.
+        let mut base = Extended::from_dec64(self).abs();
+        let mut power = Extended::ONE;
+        let mut remaining = exponent.unsigned_abs();
+        loop {
+            if remaining % 2 == 1 {
+                power = power.mul(base);
+            }
+            remaining /= 2;
+            if remaining == 0 {
+                break;
+            }
+            base = base.mul(base);
+        }
+        if exponent < 0 {
+            power = Extended::ONE.div(power);
+        }
+        if self.coefficient() < 0 && exponent % 2 != 0 {
+            power = power.neg();
+        }
+        power.round()
+    }
+
+    /// Raises the number to any power, rounded to nearest.
+    ///
+    /// Integer exponents behave like [`powi`](Dec64::powi). Other powers are computed as `e^(exponent × ln(self))`
+    /// with 38-digit intermediate results, so they are off by more than half a unit in the last place only
+    /// when the exact result is extremely close to halfway between two DEC64 numbers.
+    ///
+    /// Negative numbers to non-integer powers and zero to negative powers are NaN,
+    /// as well as results that are too large. Results that are too small become zero.
+    ///
+    /// ```
+    /// # use dec64::{Dec64, consts::*};
+    /// assert_eq!(FOUR.powf(HALF), TWO);
+    /// assert_eq!(TWO.powf(HALF), TWO.sqrt());
+    /// assert_eq!(Dec64::from(8).powf(-Dec64::from_parts(25, -1)), Dec64::from_parts(5524271728019903, -18));
+    /// ```
+    pub fn powf(self, exponent: Dec64) -> Dec64 {
+        if self.is_nan() || exponent.is_nan() {
+            return NAN;
+        }
+        if let Ok(exponent) = i32::try_from(exponent) {
+            return self.powi(exponent);
+        }
+        let integral = exponent.is_integer();
+        if self.is_zero() {
+            return if exponent.coefficient() > 0 {
+                ZERO
+            } else {
+                NAN
+            };
+        }
+        if self.coefficient() < 0 && !integral {
+            return NAN;
+        }
+
+        let magnitude = Extended::from_dec64(self).abs();
+        let power = Extended::from_dec64(exponent).mul(magnitude.ln()).exp();
+        // Only integral exponents remain for negative numbers, and odd ones keep the sign.
+        if self.coefficient() < 0 && !(exponent % TWO).is_zero() {
+            power.neg().round()
+        } else {
+            power.round()
+        }
+    }
+}
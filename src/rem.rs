@@ -0,0 +1,130 @@
+//! Integral division and remainders of DEC64.
+
+use crate::{Dec64, NAN, RoundingMode, Wide, ZERO};
+
+/// The integral quotient is kept below this limit so that another digit can be appended within 128 bits.
+const QUOTIENT_LIMIT: u128 = 10u128.pow(37);
+
+impl Dec64 {
+    /// Divides this number by `rhs`, returning the integral quotient rounded towards zero,
+    /// as well as the remainder, which has the same sign as this number.
+    ///
+    /// The remainder is always exact, while the quotient is rounded like any other result
+    /// if it has more digits than a DEC64 can hold. Returns NaN for both if either operand is NaN
+    /// or the divisor is zero.
+    ///
+    /// ```
+    /// # use dec64::{Dec64, consts::*};
+    /// let installment = Dec64::from_parts(3333, -2);
+    /// assert_eq!(Dec64::from(100).div_rem(installment), (THREE, CENT));
+    /// ```
+    #[inline]
+    pub fn div_rem(self, rhs: Dec64) -> (Dec64, Dec64) {
+        self.divide_integral(rhs, false)
+    }
+
+    /// Euclidean division: the integral quotient `q` such that `self = q × rhs + r` with `0 ≤ r < |rhs|`.
+    ///
+    /// This is [`floor`](Self::floor) of the exact quotient for positive `rhs`, and [`ceil`](Self::ceil) for negative `rhs`.
+    #[inline]
+    pub fn div_euclid(self, rhs: Dec64) -> Dec64 {
+        self.divide_integral(rhs, self.coefficient() < 0).0
+    }
+
+    /// Euclidean remainder, which is never negative. See [`Self::div_euclid`].
+    ///
+    /// The remainder is exact unless it has more digits than a DEC64 can hold,
+    /// which may happen for negative numbers far smaller than `rhs`.
+    #[inline]
+    pub fn rem_euclid(self, rhs: Dec64) -> Dec64 {
+        self.divide_integral(rhs, self.coefficient() < 0).1
+    }
+
+    /// Computes the integral quotient rounded towards zero or away from zero, and the matching remainder.
+    fn divide_integral(self, rhs: Dec64, away_from_zero: bool) -> (Dec64, Dec64) {
+        if self.is_nan() || rhs.is_nan() || rhs.is_zero() {
+            return (NAN, NAN);
+        }
+        if self.is_zero() {
+            return (ZERO, ZERO);
+        }
+
+        let dividend = self.coefficient().unsigned_abs() as u128;
+        let divisor = rhs.coefficient().unsigned_abs() as u128;
+        let lhs_exponent = self.exponent() as i32;
+        let rhs_exponent = rhs.exponent() as i32;
+        // The remainder has the exponent of the more precise operand.
+        let remainder_exponent = lhs_exponent.min(rhs_exponent);
+
+        let mut quotient;
+        let mut remainder;
+        // Digits of the quotient that didn’t fit, and whether they were nonzero or all nines.
+        let mut dropped = 0;
+        let mut sticky = false;
+        let mut all_nines = true;
+        if lhs_exponent >= rhs_exponent {
+            quotient = dividend / divisor;
+            remainder = dividend % divisor;
+            // Long division for each digit that the dividend is scaled up by.
+            for _ in rhs_exponent..lhs_exponent {
+                let scaled = remainder * 10;
+                let digit = scaled / divisor;
+                remainder = scaled % divisor;
+                if dropped == 0 && quotient < QUOTIENT_LIMIT {
+                    quotient = quotient * 10 + digit;
+                } else {
+                    dropped += 1;
+                    sticky |= digit != 0;
+                    all_nines &= digit == 9;
+                }
+            }
+        } else {
+            let scaled_divisor = 10u128
+                .checked_pow((rhs_exponent - lhs_exponent) as u32)
+                .and_then(|power| power.checked_mul(divisor));
+            (quotient, remainder) = match scaled_divisor {
+                Some(scaled_divisor) => (dividend / scaled_divisor, dividend % scaled_divisor),
+                // The divisor is far larger than the dividend.
+                None => (0, dividend),
+            };
+        }
+
+        let remainder = if away_from_zero && remainder != 0 {
+            if dropped == 0 || all_nines {
+                // Incrementing carries into the kept digits.
+                quotient += 1;
+                sticky = false;
+            } else {
+                sticky = true;
+            }
+            // The remainder becomes |rhs| - |remainder|, with the opposite sign.
+            -Wide::sum(
+                divisor as i64,
+                rhs_exponent as i8,
+                -(remainder as i64),
+                remainder_exponent as i8,
+            )
+            .round(RoundingMode::HalfUp)
+            .0
+        } else {
+            Dec64::new(remainder as i64, remainder_exponent)
+        };
+
+        let negative_quotient = (self.coefficient() < 0) != (rhs.coefficient() < 0);
+        let quotient = Wide {
+            coefficient: if negative_quotient {
+                -(quotient as i128)
+            } else {
+                quotient as i128
+            },
+            exponent: dropped,
+            sticky,
+        };
+        let remainder = if self.coefficient() < 0 {
+            -remainder
+        } else {
+            remainder
+        };
+        (quotient.round(RoundingMode::HalfUp).0, remainder)
+    }
+}
@@ -0,0 +1,240 @@
+//! Square and cube roots of DEC64.
+//!
+//! All roots are computed exactly on integers and rounded once, half away from zero like the operators.
+//! The integer radicand is scaled so that its root has more digits than a DEC64 coefficient,
+//! and whether the root is exact decides how ties are broken.
+
+use crate::{Dec64, NAN, RoundingMode, Wide, ZERO};
+
+/// Square roots of radicands with at least this many digits have more digits than a DEC64 coefficient.
+const SQUARE_DIGITS: u32 = 37;
+/// Cube roots of radicands with at least this many digits have more digits than a DEC64 coefficient.
+const CUBE_DIGITS: u32 = 52;
+
+/// Returns the number of decimal digits of a nonzero number.
+#[inline]
+fn digits(number: u128) -> u32 {
+    number.ilog10() + 1
+}
+
+/// Computes `⌊number × 10^scale / divisor⌋` by long division, and whether the division has a remainder.
+///
+/// `divisor × 10` must fit into 128 bits, as well as the quotient.
+fn scaled_quotient(number: u128, scale: u32, divisor: u128) -> (u128, bool) {
+    let mut quotient = number / divisor;
+    let mut remainder = number % divisor;
+    for _ in 0..scale {
+        remainder *= 10;
+        quotient = quotient * 10 + remainder / divisor;
+        remainder %= divisor;
+    }
+    (quotient, remainder != 0)
+}
+
+/// Strips trailing zeros from an exact root as long as the exponent stays below the preferred exponent.
+fn exact_root(mut root: u128, mut exponent: i32, preferred_exponent: i32) -> Wide {
+    while exponent < preferred_exponent && root.is_multiple_of(10) {
+        root /= 10;
+        exponent += 1;
+    }
+    Wide::exact(root as i128, exponent)
+}
+
+/// The square root of `radicand × 10^exponent`.
+///
+/// If `sticky` is set, the radicand is followed by further nonzero digits;
+/// it must then have more than [`SQUARE_DIGITS`] digits, so that no digits have to be appended.
+fn square_root(
+    mut radicand: u128,
+    mut exponent: i32,
+    mut sticky: bool,
+    preferred_exponent: i32,
+) -> Wide {
+    // Scale to 37 or 38 digits with an even exponent.
+    while digits(radicand) < SQUARE_DIGITS {
+        radicand *= 10;
+        exponent -= 1;
+    }
+    while digits(radicand) > SQUARE_DIGITS + 1
+        || (exponent % 2 != 0 && digits(radicand) > SQUARE_DIGITS)
+    {
+        sticky |= !radicand.is_multiple_of(10);
+        radicand /= 10;
+        exponent += 1;
+    }
+    if exponent % 2 != 0 {
+        radicand *= 10;
+        exponent -= 1;
+    }
+
+    let root = radicand.isqrt();
+    if !sticky && root * root == radicand {
+        exact_root(root, exponent / 2, preferred_exponent)
+    } else {
+        Wide {
+            coefficient: root as i128,
+            exponent: exponent / 2,
+            sticky: true,
+        }
+    }
+}
+
+impl Dec64 {
+    /// Returns the square root, correctly rounded.
+    ///
+    /// Returns NaN for NaN and negative numbers. Roots of perfect squares are exact:
+    ///
+    /// ```
+    /// # use dec64::{Dec64, consts::*};
+    /// assert_eq!(Dec64::from_parts(225, -2).sqrt(), Dec64::from_parts(15, -1));
+    /// assert_eq!(TWO.sqrt(), Dec64::from_parts(14142135623730950, -16));
+    /// ```
+    pub fn sqrt(self) -> Dec64 {
+        if self.is_nan() || self.coefficient() < 0 {
+            return NAN;
+        }
+        if self.is_zero() {
+            return ZERO;
+        }
+        let exponent = self.exponent() as i32;
+        square_root(
+            self.coefficient() as u128,
+            exponent,
+            false,
+            exponent.div_euclid(2),
+        )
+        .round(RoundingMode::HalfUp)
+        .0
+    }
+
+    /// Returns the reciprocal of the square root, correctly rounded.
+    ///
+    /// Returns NaN for NaN, zero and negative numbers.
+    ///
+    /// ```
+    /// # use dec64::{Dec64, consts::*};
+    /// assert_eq!(Dec64::from_parts(4, -2).rsqrt(), FIVE);
+    /// ```
+    pub fn rsqrt(self) -> Dec64 {
+        if self.is_nan() || self.coefficient() <= 0 {
+            return NAN;
+        }
+        // 1 / (c × 10^e) = (10^scale / c) × 10^(-scale - e), where the quotient has 38 digits.
+        let coefficient = self.coefficient() as u128;
+        let scale = SQUARE_DIGITS + digits(coefficient);
+        let (reciprocal, inexact) = scaled_quotient(1, scale, coefficient);
+        let exponent = -(scale as i32) - self.exponent() as i32;
+        square_root(reciprocal, exponent, inexact, exponent.div_euclid(2))
+            .round(RoundingMode::HalfUp)
+            .0
+    }
+
+    /// Returns the cube root, correctly rounded. Negative numbers have a negative cube root.
+    ///
+    /// Returns NaN for NaN. Roots of perfect cubes are exact:
+    ///
+    /// ```
+    /// # use dec64::{Dec64, consts::*};
+    /// assert_eq!(Dec64::from_parts(-27, -3).cbrt(), Dec64::from_parts(-3, -1));
+    /// ```
+    pub fn cbrt(self) -> Dec64 {
+        if self.is_nan() {
+            return NAN;
+        }
+        if self.is_zero() {
+            return ZERO;
+        }
+
+        // Scale the magnitude to 52 to 54 digits with an exponent divisible by three,
+        // so that the root has 18 digits.
+        let magnitude = self.coefficient().unsigned_abs() as u128;
+        let exponent = self.exponent() as i32;
+        let mut scale = CUBE_DIGITS - digits(magnitude);
+        while (exponent - scale as i32) % 3 != 0 {
+            scale += 1;
+        }
+
+        // Newton’s method on integers converges to the floor of the root from above,
+        // so start with a power of ten that is larger than any root.
+        let mut root = 10u128.pow(CUBE_DIGITS / 3 + 1);
+        loop {
+            let (quotient, _) = scaled_quotient(magnitude, scale, root * root);
+            let next = (2 * root + quotient) / 3;
+            if next >= root {
+                break;
+            }
+            root = next;
+        }
+        let (quotient, inexact) = scaled_quotient(magnitude, scale, root * root);
+        let exponent = (exponent - scale as i32) / 3;
+        let root = if !inexact && quotient == root {
+            exact_root(root, exponent, self.exponent().div_euclid(3) as i32)
+        } else {
+            Wide {
+                coefficient: root as i128,
+                exponent,
+                sticky: true,
+            }
+        };
+
+        let root = if self.coefficient() < 0 {
+            Wide {
+                coefficient: -root.coefficient,
+                ..root
+            }
+        } else {
+            root
+        };
+        root.round(RoundingMode::HalfUp).0
+    }
+
+    /// Returns the length of the hypotenuse of a right-angle triangle with legs `self` and `other`,
+    /// i.e. `sqrt(self² + other²)`, correctly rounded and without intermediate overflow or underflow.
+    ///
+    /// Returns NaN if either number is NaN.
+    ///
+    /// ```
+    /// # use dec64::{Dec64, consts::*};
+    /// assert_eq!(THREE.hypot(-FOUR), FIVE);
+    /// ```
+    pub fn hypot(self, other: Dec64) -> Dec64 {
+        if self.is_nan() || other.is_nan() {
+            return NAN;
+        }
+        if self.is_zero() {
+            return other.abs();
+        }
+        if other.is_zero() {
+            return self.abs();
+        }
+
+        // Sort the squares by magnitude.
+        let square = |value: Dec64| {
+            let coefficient = value.coefficient().unsigned_abs() as u128;
+            (coefficient * coefficient, 2 * value.exponent() as i32)
+        };
+        let leading = |(square, exponent): (u128, i32)| digits(square) as i32 + exponent;
+        let (larger, smaller) = match (square(self), square(other)) {
+            (lhs, rhs) if leading(lhs) >= leading(rhs) => (lhs, rhs),
+            (lhs, rhs) => (rhs, lhs),
+        };
+
+        // Align both squares to the larger one scaled to 38 digits.
+        // The smaller one then has at most 38 digits as well, and its lower digits may be cut off.
+        let scale = SQUARE_DIGITS + 1 - digits(larger.0);
+        let exponent = larger.1 - scale as i32;
+        let shift = smaller.1 - exponent;
+        let (smaller, sticky) = if shift >= 0 {
+            (smaller.0 * 10u128.pow(shift as u32), false)
+        } else {
+            match 10u128.checked_pow(shift.unsigned_abs()) {
+                Some(divisor) => (smaller.0 / divisor, smaller.0 % divisor != 0),
+                None => (0, true),
+            }
+        };
+        let sum = larger.0 * 10u128.pow(scale) + smaller;
+        square_root(sum, exponent, sticky, exponent.div_euclid(2))
+            .round(RoundingMode::HalfUp)
+            .0
+    }
+}
@@ -0,0 +1,72 @@
+//! Rounding modes.
+
+use core::cmp::Ordering;
+
+/// How a result that cannot be represented exactly is rounded.
+///
+/// The operators and [`Dec64::new`](crate::Dec64::new) always use [`RoundingMode::HalfUp`],
+/// other modes are available through the functions with a `_rounded` or `_with` suffix.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum RoundingMode {
+    /// Round to the nearest number, and ties to the number with an even last digit.
+    /// This is also known as banker’s rounding.
+    HalfEven,
+    /// Round to the nearest number, and ties away from zero.
+    #[default]
+    HalfUp,
+    /// Round to the nearest number, and ties towards zero.
+    HalfDown,
+    /// Round towards zero, i.e. truncate.
+    TowardZero,
+    /// Round away from zero.
+    AwayFromZero,
+    /// Round towards negative infinity.
+    Floor,
+    /// Round towards positive infinity.
+    Ceiling,
+}
+
+/// The size of discarded digits relative to half a unit of the last kept digit.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum Discarded {
+    Zero,
+    BelowHalf,
+    Half,
+    AboveHalf,
+}
+
+impl Discarded {
+    /// Classifies a nonnegative remainder, with `sticky` signalling further nonzero digits below it.
+    #[inline]
+    pub fn classify(remainder: u128, half: u128, sticky: bool) -> Self {
+        if remainder == 0 && !sticky {
+            return Self::Zero;
+        }
+        match remainder.cmp(&half) {
+            Ordering::Less => Self::BelowHalf,
+            Ordering::Equal if !sticky => Self::Half,
+            _ => Self::AboveHalf,
+        }
+    }
+}
+
+impl RoundingMode {
+    /// Returns whether a truncated number has to be rounded up in magnitude.
+    ///
+    /// `negative` is the sign of the number, and `odd` tells whether the last kept digit is odd.
+    #[inline]
+    pub(crate) fn rounds_away(self, discarded: Discarded, negative: bool, odd: bool) -> bool {
+        if discarded == Discarded::Zero {
+            return false;
+        }
+        match self {
+            Self::HalfEven => discarded > Discarded::Half || (discarded == Discarded::Half && odd),
+            Self::HalfUp => discarded >= Discarded::Half,
+            Self::HalfDown => discarded > Discarded::Half,
+            Self::TowardZero => false,
+            Self::AwayFromZero => true,
+            Self::Floor => negative,
+            Self::Ceiling => !negative,
+        }
+    }
+}
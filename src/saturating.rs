@@ -0,0 +1,69 @@
+//! Saturating arithmetic on DEC64.
+//!
+//! Instead of becoming NaN, results that are too large in magnitude are clamped to [`MAX`] or [`MIN`].
+//! Results that are too small in magnitude become zero, just like with the operators in [`core::ops`].
+
+use crate::{Dec64, MAX, MIN, NAN};
+
+impl Dec64 {
+    /// Saturating addition. Returns [`MAX`] or [`MIN`] if the sum overflows,
+    /// and NaN if either operand is NaN.
+    #[inline]
+    pub fn saturating_add(self, other: Dec64) -> Dec64 {
+        let sum = self + other;
+        if sum.is_nan() && !self.is_nan() && !other.is_nan() {
+            // Only operands of the same sign can overflow.
+            Self::saturate(self.sign() + other.sign())
+        } else {
+            sum
+        }
+    }
+
+    /// Saturating subtraction. Returns [`MAX`] or [`MIN`] if the difference overflows,
+    /// and NaN if either operand is NaN.
+    #[inline]
+    pub fn saturating_sub(self, other: Dec64) -> Dec64 {
+        let difference = self - other;
+        if difference.is_nan() && !self.is_nan() && !other.is_nan() {
+            // Only operands of different sign can overflow.
+            Self::saturate(self.sign() - other.sign())
+        } else {
+            difference
+        }
+    }
+
+    /// Saturating multiplication. Returns [`MAX`] or [`MIN`] if the product overflows,
+    /// zero if it underflows, and NaN if either operand is NaN.
+    #[inline]
+    pub fn saturating_mul(self, other: Dec64) -> Dec64 {
+        let product = self * other;
+        if product.is_nan() && !self.is_nan() && !other.is_nan() {
+            Self::saturate(self.sign() * other.sign())
+        } else {
+            product
+        }
+    }
+
+    /// Saturating division. Returns [`MAX`] or [`MIN`] if the quotient overflows,
+    /// and zero if it underflows.
+    ///
+    /// Division by zero is not an overflow, so it returns NaN just like division of a NaN operand.
+    #[inline]
+    pub fn saturating_div(self, other: Dec64) -> Dec64 {
+        if other.is_zero() {
+            return NAN;
+        }
+        let quotient = self / other;
+        if quotient.is_nan() && !self.is_nan() && !other.is_nan() {
+            Self::saturate(self.sign() * other.sign())
+        } else {
+            quotient
+        }
+    }
+
+    /// Returns the number closest to infinity with the given sign.
+    #[inline]
+    fn saturate(sign: i8) -> Dec64 {
+        if sign < 0 { MIN } else { MAX }
+    }
+}
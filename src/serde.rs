@@ -0,0 +1,202 @@
+//! [Serde](https://serde.rs) support, enabled by the `serde` feature.
+//!
+//! By default, a [`Dec64`] is serialized as its exact decimal string,
+//! and can be deserialized from strings, integers and floats.
+//! Formats that are not human-readable only deserialize it from the string.
+//! With the `arbitrary_precision` feature of `serde_json`, JSON numbers are read without going through a float,
+//! so they are as exact as when they are given as a string.
+//!
+//! The modules in here provide other representations for use with `#[serde(with = "…")]`:
+//!
+//! ```
+//! # use dec64::Dec64;
+//! # use serde::{Deserialize, Serialize};
+//! #[derive(Serialize, Deserialize)]
+//! struct Payment {
+//!     amount: Dec64,
+//!     #[serde(with = "dec64::serde::as_f64")]
+//!     fee: Dec64,
+//! }
+//! ```
+
+use core::fmt::{self, Formatter};
+
+use ::serde::de::{self, Deserializer, MapAccess, Unexpected, Visitor};
+use ::serde::{Deserialize, Serialize, Serializer};
+
+use crate::Dec64;
+
+/// The map key under which `serde_json` with `arbitrary_precision` passes the text of a number.
+const JSON_NUMBER_TOKEN: &str = "$serde_json::private::Number";
+
+impl Serialize for Dec64 {
+    /// Serializes the exact decimal string of the [`Display`](core::fmt::Display) implementation.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Dec64 {
+    /// Deserializes a decimal string, an integer or a float.
+    ///
+    /// Strings are parsed exactly like [`str::parse`], and numbers are converted with [`From`].
+    /// Formats that are not human-readable, like bincode, are not self-describing,
+    /// so they are read as the string that [`Serialize`] writes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Dec64, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(NumberVisitor)
+        } else {
+            deserializer.deserialize_str(NumberVisitor)
+        }
+    }
+}
+
+/// Parses a string into a DEC64.
+fn parse_str<E: de::Error>(text: &str, expected: &dyn de::Expected) -> Result<Dec64, E> {
+    text.parse()
+        .map_err(|_| E::invalid_value(Unexpected::Str(text), expected))
+}
+
+/// Accepts strings, integers, floats and `serde_json` numbers with arbitrary precision.
+struct NumberVisitor;
+
+impl<'de> Visitor<'de> for NumberVisitor {
+    type Value = Dec64;
+
+    fn expecting(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("a decimal number or string")
+    }
+
+    fn visit_str<E: de::Error>(self, text: &str) -> Result<Dec64, E> {
+        parse_str(text, &self)
+    }
+
+    fn visit_i64<E: de::Error>(self, number: i64) -> Result<Dec64, E> {
+        Ok(Dec64::from(number))
+    }
+
+    fn visit_u64<E: de::Error>(self, number: u64) -> Result<Dec64, E> {
+        Ok(Dec64::from(number))
+    }
+
+    fn visit_i128<E: de::Error>(self, number: i128) -> Result<Dec64, E> {
+        Ok(Dec64::from(number))
+    }
+
+    fn visit_u128<E: de::Error>(self, number: u128) -> Result<Dec64, E> {
+        Ok(Dec64::from(number))
+    }
+
+    fn visit_f64<E: de::Error>(self, number: f64) -> Result<Dec64, E> {
+        Ok(Dec64::from(number))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Dec64, A::Error> {
+        if map.next_key::<JsonNumberKey>()?.is_none() {
+            return Err(de::Error::invalid_type(Unexpected::Map, &self));
+        }
+        Ok(map.next_value::<DecimalString>()?.0)
+    }
+}
+
+/// The key of a `serde_json` number with arbitrary precision.
+struct JsonNumberKey;
+
+impl<'de> Deserialize<'de> for JsonNumberKey {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct KeyVisitor;
+
+        impl Visitor<'_> for KeyVisitor {
+            type Value = JsonNumberKey;
+
+            fn expecting(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                f.write_str("a serde_json number")
+            }
+
+            fn visit_str<E: de::Error>(self, key: &str) -> Result<JsonNumberKey, E> {
+                if key == JSON_NUMBER_TOKEN {
+                    Ok(JsonNumberKey)
+                } else {
+                    Err(E::invalid_value(Unexpected::Str(key), &self))
+                }
+            }
+        }
+
+        deserializer.deserialize_identifier(KeyVisitor)
+    }
+}
+
+/// A DEC64 that can only be deserialized from a string.
+struct DecimalString(Dec64);
+
+impl<'de> Deserialize<'de> for DecimalString {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct StringVisitor;
+
+        impl Visitor<'_> for StringVisitor {
+            type Value = DecimalString;
+
+            fn expecting(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                f.write_str("a decimal string")
+            }
+
+            fn visit_str<E: de::Error>(self, text: &str) -> Result<DecimalString, E> {
+                parse_str(text, &self).map(DecimalString)
+            }
+        }
+
+        deserializer.deserialize_str(StringVisitor)
+    }
+}
+
+/// Serializes as the exact decimal string and only deserializes from strings.
+///
+/// This is the same as the default representation, except that numbers are rejected when deserializing.
+pub mod as_str {
+    use super::*;
+
+    /// Serializes the exact decimal string.
+    pub fn serialize<S: Serializer>(value: &Dec64, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(value)
+    }
+
+    /// Deserializes a decimal string.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Dec64, D::Error> {
+        DecimalString::deserialize(deserializer).map(|string| string.0)
+    }
+}
+
+/// Serializes as an [`f64`], which may lose precision, and deserializes from any number.
+///
+/// See [`From<Dec64> for f64`](f64#impl-From<Dec64>-for-f64) and [`From<f64> for Dec64`](Dec64#impl-From<f64>-for-Dec64)
+/// for how the conversion rounds.
+pub mod as_f64 {
+    use super::*;
+
+    /// Serializes the nearest [`f64`].
+    pub fn serialize<S: Serializer>(value: &Dec64, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_f64(f64::from(*value))
+    }
+
+    /// Deserializes an [`f64`] and converts it to the shortest DEC64 that round-trips.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Dec64, D::Error> {
+        f64::deserialize(deserializer).map(Dec64::from)
+    }
+}
+
+/// Serializes as the raw bitpattern in an [`i64`], see [`Dec64::to_raw`] and [`Dec64::from_raw`].
+///
+/// This is the most compact and fastest representation, but it is not human-readable.
+pub mod as_raw_i64 {
+    use super::*;
+
+    /// Serializes the raw bitpattern.
+    pub fn serialize<S: Serializer>(value: &Dec64, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i64(value.to_raw())
+    }
+
+    /// Deserializes a raw bitpattern.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Dec64, D::Error> {
+        i64::deserialize(deserializer).map(Dec64::from_raw)
+    }
+}
@@ -0,0 +1,210 @@
+//! Trigonometric functions of DEC64.
+//!
+//! Arguments are reduced to about `[-π/4, π/4]` by subtracting a multiple of π/2. Like the Payne–Hanek reduction
+//! of binary floats, this multiplies with enough digits of 2/π to cover the whole exponent range of DEC64,
+//! so that even huge arguments keep their full precision. The reduced argument is evaluated with Taylor series
+//! with 38-digit intermediate results and rounded once, half away from zero, so results are the same on every platform.
+//! They are always within one unit in the last place, and correctly rounded unless the exact result is extremely
+//! close to halfway between two DEC64 numbers.
+
+use crate::extended::Extended;
+use crate::{Dec64, FRAC_PI_4, NAN};
+
+/// The first 200 fractional digits of 2/π, which cover the largest exponent and all fractional digits.
+const TWO_OVER_PI: &[u8; 200] = b"\
+    63661977236758134307553505349005744813783858296182579499066937623558719053690614036045521106501234382429137090703183214757164738445831461151186964292679935691695986774963631029231098558770123075486957";
+/// Number of fractional digits of the argument in quarter turns, i.e. as a multiple of π/2.
+const FRACTION_DIGITS: i64 = 72;
+/// Base of the limbs that the argument in quarter turns is computed in.
+const LIMB: u128 = 10u128.pow(LIMB_DIGITS as u32);
+/// Number of digits of a limb.
+const LIMB_DIGITS: i64 = 18;
+
+/// π/2 with 38 digits.
+const FRAC_PI_2: Extended = Extended {
+    inexact: true,
+    ..Extended::constant(15707963267948966192313216916397514421, -37)
+};
+/// 180/π with 38 digits.
+const DEGREES_PER_RADIAN: Extended = Extended {
+    inexact: true,
+    ..Extended::constant(57295779513082320876798154814105170332, -36)
+};
+/// π/180 with 38 digits.
+const RADIANS_PER_DEGREE: Extended = Extended {
+    inexact: true,
+    ..Extended::constant(17453292519943295769236907684886127134, -39)
+};
+
+/// Reduces the magnitude of a non-NaN number to `k × π/2 + r` with `|r| ≤ π/4`, and returns `k mod 4` and `r`.
+fn reduce(value: Dec64) -> (u8, Extended) {
+    if value.abs() < FRAC_PI_4 {
+        return (0, Extended::from_dec64(value).abs());
+    }
+
+    // |x| × 2/π = c × Σ dᵢ × 10^(e - i) with the digits dᵢ of 2/π. Multiples of 100 × c don’t change k mod 4,
+    // so only the digits from the tens place down are needed. They are scaled to an integer in limbs, lowest first.
+    let coefficient = value.coefficient().unsigned_abs() as u128;
+    let exponent = value.exponent() as i64;
+    let digit = |place: i64| match usize::try_from(exponent - place) {
+        Ok(index @ 1..) => TWO_OVER_PI
+            .get(index - 1)
+            .map_or(0, |digit| (digit - b'0') as u128),
+        _ => 0,
+    };
+    let mut limbs = [0u128; 6];
+    for (index, limb) in limbs.iter_mut().enumerate() {
+        let lowest = index as i64 * LIMB_DIGITS - FRACTION_DIGITS;
+        *limb = (lowest..lowest + LIMB_DIGITS)
+            .rev()
+            .filter(|&place| place <= 1)
+            .fold(0, |limb, place| limb * 10 + digit(place));
+    }
+    let mut carry = 0;
+    for limb in &mut limbs {
+        let product = *limb * coefficient + carry;
+        *limb = product % LIMB;
+        carry = product / LIMB;
+    }
+
+    // The limbs below the units are the fraction of a quarter turn; round to the nearest quarter turn.
+    let mut quadrant = (limbs[4] % 4) as u8;
+    let mut fraction = [limbs[0], limbs[1], limbs[2], limbs[3]];
+    let negative = fraction[3] >= LIMB / 2;
+    if negative {
+        quadrant = (quadrant + 1) % 4;
+        let mut borrow = 0;
+        for limb in &mut fraction {
+            let subtrahend = *limb + borrow;
+            (*limb, borrow) = if subtrahend == 0 {
+                (0, 0)
+            } else {
+                (LIMB - subtrahend, 1)
+            };
+        }
+    }
+
+    // Take 38 digits from the highest nonzero limb.
+    let Some(high) = fraction.iter().rposition(|&limb| limb != 0) else {
+        return (quadrant, Extended::ZERO);
+    };
+    let limb = |index: Option<usize>| index.map_or(0, |index| fraction[index]);
+    let mantissa = (fraction[high] * LIMB + limb(high.checked_sub(1))) * 100
+        + limb(high.checked_sub(2)) / (LIMB / 100);
+    let exponent = high as i64 * LIMB_DIGITS - LIMB_DIGITS - 2 - FRACTION_DIGITS;
+    let turns = Extended::new(negative, mantissa, exponent, true);
+    (quadrant, turns.mul(FRAC_PI_2))
+}
+
+/// The sine and cosine with 38 digits.
+fn sin_cos(value: Dec64) -> (Extended, Extended) {
+    let (quadrant, reduced) = reduce(value);
+    let (sine, cosine) = reduced.sin_cos();
+    let (sine, cosine) = match quadrant {
+        0 => (sine, cosine),
+        1 => (cosine, sine.neg()),
+        2 => (sine.neg(), cosine.neg()),
+        _ => (cosine.neg(), sine),
+    };
+    if value.coefficient() < 0 {
+        (sine.neg(), cosine)
+    } else {
+        (sine, cosine)
+    }
+}
+
+impl Dec64 {
+    /// Returns the sine of a number in radians.
+    ///
+    /// Returns NaN for NaN.
+    ///
+    /// ```
+    /// # use dec64::{Dec64, consts::*};
+    /// assert_eq!(FRAC_PI_2.sin(), ONE);
+    /// assert_eq!(PI.sin(), Dec64::from_parts(3846264338327950, -32));
+    /// ```
+    pub fn sin(self) -> Dec64 {
+        if self.is_nan() {
+            return NAN;
+        }
+        sin_cos(self).0.round()
+    }
+
+    /// Returns the cosine of a number in radians.
+    ///
+    /// Returns NaN for NaN.
+    ///
+    /// ```
+    /// # use dec64::{Dec64, consts::*};
+    /// assert_eq!(PI.cos(), NEGATIVE_ONE);
+    /// assert_eq!(Dec64::from_parts(1, 22).cos(), Dec64::from_parts(5232147853951389, -16));
+    /// ```
+    pub fn cos(self) -> Dec64 {
+        if self.is_nan() {
+            return NAN;
+        }
+        sin_cos(self).1.round()
+    }
+
+    /// Returns the tangent of a number in radians.
+    ///
+    /// Returns NaN for NaN.
+    ///
+    /// ```
+    /// # use dec64::{Dec64, consts::*};
+    /// assert_eq!(ZERO.tan(), ZERO);
+    /// assert_eq!(ONE.tan(), Dec64::from_parts(15574077246549022, -16));
+    /// ```
+    pub fn tan(self) -> Dec64 {
+        if self.is_nan() {
+            return NAN;
+        }
+        let (sine, cosine) = sin_cos(self);
+        if cosine.is_zero() {
+            return NAN;
+        }
+        sine.div(cosine).round()
+    }
+
+    /// Returns the sine and the cosine of a number in radians, which is faster than computing them separately.
+    ///
+    /// Returns NaN for both for NaN.
+    ///
+    /// ```
+    /// # use dec64::{Dec64, consts::*};
+    /// assert_eq!(ZERO.sin_cos(), (ZERO, ONE));
+    /// ```
+    pub fn sin_cos(self) -> (Dec64, Dec64) {
+        if self.is_nan() {
+            return (NAN, NAN);
+        }
+        let (sine, cosine) = sin_cos(self);
+        (sine.round(), cosine.round())
+    }
+
+    /// Converts radians to degrees.
+    ///
+    /// ```
+    /// # use dec64::{Dec64, consts::*};
+    /// assert_eq!(PI.to_degrees(), Dec64::from(180));
+    /// ```
+    pub fn to_degrees(self) -> Dec64 {
+        if self.is_nan() {
+            return NAN;
+        }
+        Extended::from_dec64(self).mul(DEGREES_PER_RADIAN).round()
+    }
+
+    /// Converts degrees to radians.
+    ///
+    /// ```
+    /// # use dec64::{Dec64, consts::*};
+    /// assert_eq!(Dec64::from(180).to_radians(), PI);
+    /// ```
+    pub fn to_radians(self) -> Dec64 {
+        if self.is_nan() {
+            return NAN;
+        }
+        Extended::from_dec64(self).mul(RADIANS_PER_DEGREE).round()
+    }
+}
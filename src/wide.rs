@@ -0,0 +1,216 @@
+//! Wide intermediate results of arithmetic, and their rounding to DEC64.
+
+use crate::rounding::Discarded;
+use crate::{Dec64, Flags, MAX_EXP, MIN_EXP, NAN, RoundingMode, ZERO};
+
+/// Number of decimal places that a DEC64 coefficient can always be scaled up by within 128 bits.
+const MAX_SCALE: u32 = 20;
+/// Largest power of 10 that fits into an [`i128`].
+const MAX_POWER_I128: u32 = 38;
+
+/// An intermediate result of arithmetic with a 128-bit coefficient.
+///
+/// The represented value is `coefficient × 10^exponent`. If `sticky` is set, some nonzero digits
+/// below the coefficient were discarded; the value is then slightly larger in magnitude than the coefficient,
+/// by less than one unit of its last digit. Sticky results always have a nonzero coefficient.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Wide {
+    pub coefficient: i128,
+    pub exponent: i32,
+    pub sticky: bool,
+}
+
+impl Wide {
+    /// An exact intermediate result.
+    #[inline]
+    pub const fn exact(coefficient: i128, exponent: i32) -> Self {
+        Self {
+            coefficient,
+            exponent,
+            sticky: false,
+        }
+    }
+
+    /// The sum of two numbers given as coefficient and exponent.
+    ///
+    /// The sum is exact if the exponents are close together. Otherwise, enough digits are kept
+    /// so that the sum can still be rounded correctly.
+    pub fn sum(
+        lhs_coefficient: i64,
+        lhs_exponent: i8,
+        rhs_coefficient: i64,
+        rhs_exponent: i8,
+    ) -> Self {
+        let ((hi_coefficient, hi_exponent), (lo_coefficient, lo_exponent)) =
+            if lhs_exponent >= rhs_exponent {
+                (
+                    (lhs_coefficient, lhs_exponent),
+                    (rhs_coefficient, rhs_exponent),
+                )
+            } else {
+                (
+                    (rhs_coefficient, rhs_exponent),
+                    (lhs_coefficient, lhs_exponent),
+                )
+            };
+        if lo_coefficient == 0 {
+            return Self::exact(hi_coefficient as i128, hi_exponent as i32);
+        }
+        if hi_coefficient == 0 {
+            return Self::exact(lo_coefficient as i128, lo_exponent as i32);
+        }
+
+        let difference = (hi_exponent as i16 - lo_exponent as i16) as u32;
+        if difference <= MAX_SCALE {
+            return Self::exact(
+                hi_coefficient as i128 * 10i128.pow(difference) + lo_coefficient as i128,
+                lo_exponent as i32,
+            );
+        }
+
+        // The exponents are too far apart, so the lower number only contributes to the rounding.
+        // Scale the higher number up as far as possible and cut off the lower number’s digits below that.
+        let shift = difference - MAX_SCALE;
+        let (quotient, remainder) = if shift > MAX_POWER_I128 {
+            (0, lo_coefficient as i128)
+        } else {
+            let divisor = 10i128.pow(shift);
+            (
+                lo_coefficient as i128 / divisor,
+                lo_coefficient as i128 % divisor,
+            )
+        };
+        let mut coefficient = hi_coefficient as i128 * 10i128.pow(MAX_SCALE) + quotient;
+        if remainder != 0 && (remainder < 0) != (coefficient < 0) {
+            // The discarded digits point toward zero; move the coefficient towards zero by one unit
+            // so that the discarded part points away from zero again.
+            coefficient -= coefficient.signum();
+        }
+        Self {
+            coefficient,
+            exponent: hi_exponent as i32 - MAX_SCALE as i32,
+            sticky: remainder != 0,
+        }
+    }
+
+    /// The exact product of two numbers.
+    #[inline]
+    pub fn product(lhs: Dec64, rhs: Dec64) -> Self {
+        Self::exact(
+            lhs.coefficient() as i128 * rhs.coefficient() as i128,
+            lhs.exponent() as i32 + rhs.exponent() as i32,
+        )
+    }
+
+    /// The quotient of two numbers, where the divisor must not be zero.
+    ///
+    /// The quotient has more digits than a DEC64 coefficient, so that it can be rounded correctly.
+    /// Exact quotients keep the exponent as close to the exponent difference of the operands as possible.
+    pub fn quotient(lhs: Dec64, rhs: Dec64) -> Self {
+        let lhs_coefficient = lhs.coefficient() as i128;
+        if lhs_coefficient == 0 {
+            return Self::exact(0, 0);
+        }
+        let preferred_exponent = lhs.exponent() as i32 - rhs.exponent() as i32;
+
+        // Scale the dividend to 38 digits, which fits into 128 bits since |coefficient| < 2^55.
+        let scale = MAX_POWER_I128 - 1 - lhs_coefficient.unsigned_abs().ilog10();
+        let dividend = lhs_coefficient * 10i128.pow(scale);
+        let divisor = rhs.coefficient() as i128;
+        let mut coefficient = dividend / divisor;
+        let remainder = dividend % divisor;
+        let mut exponent = preferred_exponent - scale as i32;
+
+        if remainder == 0 {
+            while exponent < preferred_exponent && coefficient % 10 == 0 {
+                coefficient /= 10;
+                exponent += 1;
+            }
+        }
+        // Truncating division rounds towards zero, so the remainder always points away from zero.
+        Self {
+            coefficient,
+            exponent,
+            sticky: remainder != 0,
+        }
+    }
+
+    /// Rounds to a DEC64 with the given rounding mode.
+    ///
+    /// Returns the DEC64 and the conditions that rounding signalled; no flags means that the result is exact.
+    /// Numbers that are too large become NaN, and numbers that are too small become zero.
+    pub fn round(self, mode: RoundingMode) -> (Dec64, Flags) {
+        if self.coefficient == 0 {
+            return (ZERO, Flags::empty());
+        }
+
+        // Find out how many digits must be dropped so that the coefficient fits…
+        let mut dropped = 0;
+        let mut truncated = self.coefficient;
+        while !Dec64::coefficient_in_range(truncated) {
+            truncated /= 10;
+            dropped += 1;
+        }
+        // …and so that the exponent is not too small.
+        let underflow = (MIN_EXP as i64 - self.exponent as i64).clamp(0, u32::MAX as i64) as u32;
+        let underflowed = underflow > dropped;
+        dropped = dropped.max(underflow);
+
+        loop {
+            let (coefficient, exact) = self.drop_digits(dropped, mode);
+            if !Dec64::coefficient_in_range(coefficient) {
+                // Rounding carried into a new digit, which doesn’t fit anymore.
+                dropped += 1;
+                continue;
+            }
+            let flags = match (exact, underflowed) {
+                (true, _) => Flags::empty(),
+                (false, false) => Flags::INEXACT,
+                (false, true) => Flags::INEXACT | Flags::UNDERFLOW,
+            };
+            return Self::pack(coefficient, self.exponent as i64 + dropped as i64, flags);
+        }
+    }
+
+    /// Drops the given number of digits from the coefficient, rounding with the given mode.
+    /// Returns the rounded coefficient and whether no nonzero digits were dropped.
+    pub fn drop_digits(self, count: u32, mode: RoundingMode) -> (i128, bool) {
+        let (truncated, discarded) = if count == 0 {
+            // Sticky digits are always accompanied by more digits than a DEC64 can hold,
+            // so they are never all that is dropped.
+            (self.coefficient, Discarded::classify(0, 1, self.sticky))
+        } else if count > MAX_POWER_I128 {
+            // All digits are dropped, and they are less than half of the last digit.
+            (0, Discarded::BelowHalf)
+        } else {
+            let divisor = 10i128.pow(count);
+            let remainder = (self.coefficient % divisor).unsigned_abs();
+            (
+                self.coefficient / divisor,
+                Discarded::classify(remainder, divisor.unsigned_abs() / 2, self.sticky),
+            )
+        };
+        let rounded = if mode.rounds_away(discarded, self.coefficient < 0, truncated % 2 != 0) {
+            truncated + self.coefficient.signum()
+        } else {
+            truncated
+        };
+        (rounded, discarded == Discarded::Zero)
+    }
+
+    /// Packs a rounded coefficient that is within range.
+    fn pack(mut coefficient: i128, mut exponent: i64, flags: Flags) -> (Dec64, Flags) {
+        if coefficient == 0 {
+            return (ZERO, flags);
+        }
+        // The exponent is too big. We can attempt to reduce it by scaling back.
+        while exponent > MAX_EXP as i64 && Dec64::coefficient_in_range(coefficient * 10) {
+            coefficient *= 10;
+            exponent -= 1;
+        }
+        if exponent > MAX_EXP as i64 {
+            return (NAN, Flags::OVERFLOW | Flags::INEXACT);
+        }
+        (Dec64::from_parts(coefficient as i64, exponent as i8), flags)
+    }
+}
@@ -1,138 +1,329 @@
-//! To-String ([`Display`]) implementation for DEC64.
+//! To-String ([`Display`], [`LowerExp`], [`UpperExp`]) implementations for DEC64.
 
 use crate::Dec64;
-use std::fmt::{self, Display, Formatter, Write};
-use std::ops::Range;
+use std::fmt::{self, Alignment, Display, Formatter, LowerExp, UpperExp, Write};
 
-impl Dec64 {
-    const PLACES: i16 = 0;
+/// Maximum number of decimal digits in a DEC64 coefficient.
+const MAX_DIGITS: usize = 17;
 
-    pub fn write(self, wr: &mut Formatter) -> fmt::Result {
-        let mut coefficient = self.coefficient();
-        let exponent = self.exponent();
+/// Numbers with more integer digits than this are written in scientific notation.
+const MAX_INTEGER_DIGITS: i16 = 20;
+/// Numbers below one with more fraction digits than this are written in scientific notation.
+const MAX_FRACTION_DIGITS: i16 = 18;
+
+/// The decimal digits of a DEC64 coefficient, without sign and trailing zeros.
+///
+/// The represented magnitude is `0.d₁d₂…dₙ × 10^point`.
+struct Digits {
+    buffer: [u8; MAX_DIGITS],
+    count: usize,
+    point: i16,
+}
+
+impl Digits {
+    fn new(value: Dec64) -> Self {
+        let mut coefficient = value.coefficient().unsigned_abs();
+        let mut buffer = [b'0'; MAX_DIGITS];
+        let mut count = 0;
+        let mut total_count = 0;
 
-        if self.is_zero() {
-            return wr.write_char('0');
-        } else if self.is_nan() {
-            return wr.write_str("nan");
+        // Collect digits from least to most significant, skipping trailing zeros.
+        while coefficient != 0 {
+            let digit = (coefficient % 10) as u8;
+            if digit != 0 || count != 0 {
+                buffer[count] = digit + b'0';
+                count += 1;
+            }
+            total_count += 1;
+            coefficient /= 10;
         }
+        buffer[..count].reverse();
 
-        if coefficient < 0 {
-            wr.write_char('-')?;
-            coefficient = -coefficient;
-        }
-        let mut coefficient = coefficient as usize;
-        let exponent = exponent as i16;
-
-        let mut digit_buffer = [b'0'; 32];
-        let mut digit_count = 0;
-        let mut zero_count = 0;
-        for place_i in 0..=16 {
-            let place = 16 - place_i;
-            let digit = coefficient / 10usize.pow(place);
-            digit_buffer[digit_count] = digit as u8 + b'0';
-            if digit == 0 {
-                // already have a real digit, so we’re not zero
-                if digit_count != 0 {
-                    digit_count += 1;
-                }
-                zero_count += 1;
+        Self {
+            buffer,
+            count,
+            // Zero is written with a zero exponent.
+            point: if count == 0 {
+                1
             } else {
-                digit_count += 1;
-                zero_count = 0;
+                total_count + value.exponent() as i16
+            },
+        }
+    }
+
+    /// Rounds half away from zero to the given number of significant digits.
+    fn round(&mut self, significant: usize) {
+        if significant >= self.count {
+            return;
+        }
+        let round_up = self.buffer[significant] >= b'5';
+        self.count = significant;
+        if round_up {
+            loop {
+                if self.count == 0 {
+                    // The carry propagated out of the most significant digit.
+                    self.buffer[0] = b'1';
+                    self.count = 1;
+                    self.point += 1;
+                    break;
+                }
+                if self.buffer[self.count - 1] == b'9' {
+                    self.count -= 1;
+                } else {
+                    self.buffer[self.count - 1] += 1;
+                    break;
+                }
             }
-            coefficient -= digit * 10usize.pow(place);
         }
+        while self.count > 0 && self.buffer[self.count - 1] == b'0' {
+            self.count -= 1;
+        }
+    }
 
-        if exponent >= 0 {
-            let to = digit_count as i16 + exponent;
-            if to + Self::PLACES > 20 {
-                Self::write_scientific(exponent, digit_count, zero_count, &digit_buffer, wr)?;
-            } else {
-                Self::write_digits(&digit_buffer, 0..(to as isize), wr)?;
-                if Self::PLACES > 0 {
+    /// Returns the digit at the given index, where index 0 is the most significant digit.
+    /// Indices outside the significant digits are zero.
+    #[inline]
+    fn digit_at(&self, index: i16) -> char {
+        usize::try_from(index)
+            .ok()
+            .filter(|&index| index < self.count)
+            .map_or(b'0', |index| self.buffer[index]) as char
+    }
+}
+
+impl Dec64 {
+    /// Writes this DEC64 in human-readable form.
+    ///
+    /// Without a precision, the shortest exact decimal representation is written,
+    /// switching to scientific notation for very large and very small magnitudes.
+    /// With a precision (`{:.N}`), the number is rounded half away from zero to `N` decimal places
+    /// (like [`Dec64::round_to_places`]) and always written in positional notation, padded with zeros.
+    ///
+    /// Width, fill and alignment as well as the `+` and `0` flags are honored like for the primitive number types.
+    /// Numbers are right-aligned by default.
+    pub fn write(self, wr: &mut Formatter) -> fmt::Result {
+        if self.is_nan() {
+            return Self::pad(wr, "", 3, false, |wr| wr.write_str("nan"));
+        }
+
+        let value = match wr.precision() {
+            // Only round if there actually are more fraction digits than requested.
+            Some(precision) if (self.exponent() as i64) < -(precision as i64) => {
+                self.round_to_places(-(precision as i8))
+            }
+            _ => self,
+        };
+        let sign = Self::sign_str(value, wr);
+        if value.is_zero() {
+            let places = wr.precision().unwrap_or(0);
+            let len = if places > 0 { places + 2 } else { 1 };
+            return Self::pad(wr, sign, len, true, |wr| {
+                wr.write_char('0')?;
+                if places > 0 {
                     wr.write_char('.')?;
-                    Self::write_digits(
-                        &digit_buffer,
-                        (to as isize)..((Self::PLACES + to) as isize),
-                        wr,
-                    )?;
                 }
+                Self::write_places(places, wr)
+            });
+        }
+
+        let digits = Digits::new(value);
+        let fraction_digits = (digits.count as i16 - digits.point).max(0);
+        match wr.precision() {
+            Some(places) => {
+                let len = digits.point.max(1) as usize + if places > 0 { places + 1 } else { 0 };
+                Self::pad(wr, sign, len, true, |wr| {
+                    Self::write_fixed(&digits, places, wr)
+                })
+            }
+            None if digits.point > MAX_INTEGER_DIGITS
+                || (digits.point <= 0 && fraction_digits > MAX_FRACTION_DIGITS) =>
+            {
+                let places = digits.count - 1;
+                let len = Self::scientific_len(&digits, 1, places);
+                Self::pad(wr, sign, len, true, |wr| {
+                    Self::write_scientific(&digits, 1, places, 'e', wr)
+                })
             }
+            None => {
+                let places = fraction_digits as usize;
+                let len = digits.point.max(1) as usize + if places > 0 { places + 1 } else { 0 };
+                Self::pad(wr, sign, len, true, |wr| {
+                    Self::write_fixed(&digits, places, wr)
+                })
+            }
+        }
+    }
+
+    /// Returns the sign prefix for a non-NaN number.
+    #[inline]
+    fn sign_str(value: Dec64, wr: &Formatter) -> &'static str {
+        if value.coefficient() < 0 {
+            "-"
+        } else if wr.sign_plus() {
+            "+"
         } else {
-            let from = digit_count as i16 + exponent;
-            let mut to = digit_count - zero_count;
-            if from <= 0 {
-                let places = to as i16 - from;
-                if places > 18 {
-                    Self::write_scientific(exponent, digit_count, zero_count, &digit_buffer, wr)?;
-                } else {
-                    wr.write_str("0.")?;
-                    if places < Self::PLACES {
-                        to = (Self::PLACES + from) as usize;
-                    }
-                    Self::write_digits(&digit_buffer, (from as isize)..(to as isize), wr)?;
-                }
-            } else {
-                Self::write_digits(&digit_buffer, 0..(from as isize), wr)?;
-                wr.write_char('.')?;
-                if to - (from as usize) < Self::PLACES as usize {
-                    to = (Self::PLACES + from) as usize;
-                }
-                Self::write_digits(&digit_buffer, (from as isize)..(to as isize), wr)?;
+            ""
+        }
+    }
+
+    /// Writes `sign` followed by the `len` characters written by `body`,
+    /// padded according to the formatter’s width, fill, alignment and `0` flag.
+    fn pad(
+        wr: &mut Formatter,
+        sign: &str,
+        len: usize,
+        allow_zero_pad: bool,
+        body: impl FnOnce(&mut Formatter) -> fmt::Result,
+    ) -> fmt::Result {
+        let padding = wr.width().unwrap_or(0).saturating_sub(sign.len() + len);
+        if padding == 0 {
+            wr.write_str(sign)?;
+            return body(wr);
+        }
+
+        if wr.sign_aware_zero_pad() {
+            // Zero padding goes between sign and digits, ignoring fill and alignment.
+            wr.write_str(sign)?;
+            let fill = if allow_zero_pad { '0' } else { ' ' };
+            for _ in 0..padding {
+                wr.write_char(fill)?;
             }
+            return body(wr);
+        }
+
+        let (pre, post) = match wr.align() {
+            Some(Alignment::Left) => (0, padding),
+            Some(Alignment::Center) => (padding / 2, padding - padding / 2),
+            Some(Alignment::Right) | None => (padding, 0),
+        };
+        let fill = wr.fill();
+        for _ in 0..pre {
+            wr.write_char(fill)?;
+        }
+        wr.write_str(sign)?;
+        body(wr)?;
+        for _ in 0..post {
+            wr.write_char(fill)?;
         }
         Ok(())
     }
 
+    /// Writes the digits in positional notation with exactly `places` fraction digits.
+    fn write_fixed(digits: &Digits, places: usize, wr: &mut Formatter) -> fmt::Result {
+        if digits.point <= 0 {
+            wr.write_char('0')?;
+        } else {
+            Self::write_digits(digits, 0..digits.point, wr)?;
+        }
+        if places > 0 {
+            wr.write_char('.')?;
+            let end = digits.point as i64 + places as i64;
+            // Beyond the significant digits, only zeros remain.
+            let significant_end = end.min(digits.count as i64) as i16;
+            Self::write_digits(digits, digits.point..significant_end.max(digits.point), wr)?;
+            Self::write_places(
+                (end - significant_end.max(digits.point) as i64) as usize,
+                wr,
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Returns the number of integer digits in scientific or engineering notation.
+    #[inline]
+    fn integer_digits(digits: &Digits, engineering: bool) -> i16 {
+        if engineering {
+            (digits.point - 1).rem_euclid(3) + 1
+        } else {
+            1
+        }
+    }
+
+    /// Returns the length of the notation written by [`Self::write_scientific`].
+    fn scientific_len(digits: &Digits, integer_digits: i16, places: usize) -> usize {
+        let exponent = digits.point - integer_digits;
+        let exponent_len = match exponent.unsigned_abs() {
+            0..10 => 1,
+            10..100 => 2,
+            _ => 3,
+        } + usize::from(exponent < 0);
+        integer_digits as usize + if places > 0 { places + 1 } else { 0 } + 1 + exponent_len
+    }
+
+    /// Writes the digits in scientific notation with `integer_digits` integer digits and `places` fraction digits.
     fn write_scientific(
-        exponent: i16,
-        digit_count: usize,
-        zero_count: usize,
-        digit_buffer: &[u8],
+        digits: &Digits,
+        integer_digits: i16,
+        places: usize,
+        exponent_char: char,
         wr: &mut Formatter,
     ) -> fmt::Result {
-        let adjusted_exponent = exponent + digit_count as i16;
-        let digit_count = digit_count - zero_count;
-
-        Self::write_digit_at_idx(digit_buffer, 0, wr)?;
-        if 1 < digit_count {
+        Self::write_digits(digits, 0..integer_digits, wr)?;
+        if places > 0 {
             wr.write_char('.')?;
-            Self::write_digits(digit_buffer, 1..(digit_count as isize), wr)?;
+            Self::write_digits(digits, integer_digits..integer_digits + places as i16, wr)?;
         }
-        Self::write_exponent(adjusted_exponent - 1, wr)
+        Self::write_exponent(digits.point - integer_digits, exponent_char, wr)
     }
 
-    #[inline]
-    fn write_digit_at_idx(digit_buffer: &[u8], index: isize, wr: &mut Formatter) -> fmt::Result {
-        wr.write_char(index.try_into().map_or(b'0', |index: usize| {
-            *digit_buffer.get(index).unwrap_or(&b'0')
-        }) as char)
+    /// Writes this DEC64 in scientific or engineering notation, see the [`LowerExp`] implementation.
+    fn write_exp(self, wr: &mut Formatter, exponent_char: char) -> fmt::Result {
+        if self.is_nan() {
+            return Self::pad(wr, "", 3, false, |wr| wr.write_str("nan"));
+        }
+
+        let sign = Self::sign_str(self, wr);
+        let engineering = wr.alternate();
+        let mut digits = Digits::new(self);
+        if let Some(places) = wr.precision() {
+            digits.round(Self::integer_digits(&digits, engineering) as usize + places);
+        }
+        // Rounding may have carried into a new digit, so this is computed afterwards.
+        let integer_digits = Self::integer_digits(&digits, engineering);
+        let places = wr
+            .precision()
+            .unwrap_or((digits.count as i16 - integer_digits).max(0) as usize);
+
+        let len = Self::scientific_len(&digits, integer_digits, places);
+        Self::pad(wr, sign, len, true, |wr| {
+            Self::write_scientific(&digits, integer_digits, places, exponent_char, wr)
+        })
     }
 
     #[inline]
-    fn write_digits(digit_buffer: &[u8], range: Range<isize>, wr: &mut Formatter) -> fmt::Result {
+    fn write_digits(
+        digits: &Digits,
+        range: core::ops::Range<i16>,
+        wr: &mut Formatter,
+    ) -> fmt::Result {
         for index in range {
-            Self::write_digit_at_idx(digit_buffer, index, wr)?;
+            wr.write_char(digits.digit_at(index))?;
         }
         Ok(())
     }
 
+    /// Writes `count` zeros, used for padding fraction digits up to the precision.
     #[inline]
-    fn write_exponent(mut exponent: i16, wr: &mut Formatter) -> fmt::Result {
-        if exponent == 0 {
-            return Ok(());
+    fn write_places(count: usize, wr: &mut Formatter) -> fmt::Result {
+        for _ in 0..count {
+            wr.write_char('0')?;
         }
-        wr.write_char('e')?;
+        Ok(())
+    }
+
+    #[inline]
+    fn write_exponent(mut exponent: i16, exponent_char: char, wr: &mut Formatter) -> fmt::Result {
+        wr.write_char(exponent_char)?;
         if exponent < 0 {
             exponent = -exponent;
             wr.write_char('-')?;
         }
-        if exponent >= 100 {
+        let hundreds = exponent >= 100;
+        if hundreds {
             wr.write_char('1')?;
             exponent -= 100;
         }
-        if exponent >= 10 || exponent >= 100 {
+        if exponent >= 10 || hundreds {
             wr.write_char((b'0' + (exponent / 10) as u8) as char)?;
         }
         wr.write_char((b'0' + (exponent % 10) as u8) as char)
@@ -144,3 +335,37 @@ impl Display for Dec64 {
         self.write(f)
     }
 }
+
+/// Scientific notation with the shortest exact coefficient, like `1.2e2`.
+///
+/// With a precision (`{:.Ne}`), the number is rounded half away from zero to `N` fraction digits.
+/// With the alternate flag (`{:#e}`), engineering notation is used instead,
+/// where the exponent is always a multiple of three and there are up to three integer digits.
+/// Width, fill, alignment and the `+` and `0` flags work like for [`Display`].
+///
+/// ```
+/// # use dec64::{Dec64, consts::*};
+/// assert_eq!(format!("{:e}", Dec64::from(120)), "1.2e2");
+/// assert_eq!(format!("{:.2e}", PI), "3.14e0");
+/// assert_eq!(format!("{:#e}", Dec64::from(12345)), "12.345e3");
+/// assert_eq!(format!("{:#e}", Dec64::from_parts(5, -4)), "500e-6");
+/// ```
+impl LowerExp for Dec64 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.write_exp(f, 'e')
+    }
+}
+
+/// Like [`LowerExp`], but with an uppercase `E`, including engineering notation with `{:#E}`.
+///
+/// ```
+/// # use dec64::Dec64;
+/// assert_eq!(format!("{:E}", Dec64::from_parts(-5, -3)), "-5E-3");
+/// assert_eq!(format!("{:#E}", Dec64::from_parts(-5, -3)), "-5E-3");
+/// assert_eq!(format!("{:#E}", Dec64::from_parts(15, 3)), "15E3");
+/// ```
+impl UpperExp for Dec64 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.write_exp(f, 'E')
+    }
+}
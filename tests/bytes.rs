@@ -0,0 +1,54 @@
+#![allow(clippy::wildcard_imports)]
+
+use dec64::consts::*;
+use dec64::*;
+
+#[test]
+fn bytes() {
+    let value = Dec64::from_parts(-2, -1);
+    assert_eq!(
+        value.to_be_bytes(),
+        [0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfe, 0xff]
+    );
+    assert_eq!(
+        value.to_le_bytes(),
+        [0xff, 0xfe, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff]
+    );
+    assert_eq!(value.to_ne_bytes(), value.to_raw().to_ne_bytes());
+    assert_eq!(Dec64::from_be_bytes(value.to_be_bytes()), value);
+    assert_eq!(Dec64::from_le_bytes(value.to_le_bytes()), value);
+    assert_eq!(Dec64::from_ne_bytes(value.to_ne_bytes()), value);
+    assert_eq!(Dec64::from_be_bytes([0, 0, 0, 0, 0, 0, 0, 0x80]), NAN);
+
+    // Other NaNs and zeros keep their bitpattern.
+    assert_eq!(
+        Dec64::from_le_bytes(NONNORMAL_NAN.to_le_bytes()).to_raw(),
+        NONNORMAL_NAN.to_raw()
+    );
+    assert_eq!(
+        Dec64::from_be_bytes(ZIP.to_be_bytes()).to_raw(),
+        ZIP.to_raw()
+    );
+}
+
+#[test]
+fn const_bytes() {
+    const BYTES: [u8; 8] = PI.to_be_bytes();
+    const VALUE: Dec64 = Dec64::from_be_bytes(BYTES);
+    assert_eq!(VALUE, PI);
+}
+
+#[test]
+fn io() {
+    let mut buffer = Vec::new();
+    PI.write_be(&mut buffer).unwrap();
+    NEGATIVE_TENTH.write_le(&mut buffer).unwrap();
+    assert_eq!(&buffer[..8], PI.to_be_bytes());
+    assert_eq!(&buffer[8..], NEGATIVE_TENTH.to_le_bytes());
+
+    let mut reader = buffer.as_slice();
+    assert_eq!(Dec64::read_be(&mut reader).unwrap(), PI);
+    assert_eq!(Dec64::read_le(&mut reader).unwrap(), NEGATIVE_TENTH);
+    let error = Dec64::read_be(&mut reader).unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::UnexpectedEof);
+}
@@ -0,0 +1,80 @@
+#![allow(clippy::wildcard_imports)]
+
+use dec64::consts::*;
+use dec64::*;
+
+#[test]
+fn add() {
+    assert_eq!(ONE.checked_add(ONE), Some(TWO));
+    assert_eq!(ONE.checked_add(NEGATIVE_ONE), Some(ZERO));
+    assert_eq!(MAX.checked_add(MIN), Some(MAX + MIN));
+    assert_eq!(MAX.checked_add(MAX), None);
+    assert_eq!(MIN.checked_add(MIN), None);
+    assert_eq!(NAN.checked_add(ONE), None);
+    assert_eq!(ONE.checked_add(NONNORMAL_NAN), None);
+}
+
+#[test]
+fn sub() {
+    assert_eq!(THREE.checked_sub(ONE), Some(TWO));
+    assert_eq!(MIN.checked_sub(MIN), Some(ZERO));
+    assert_eq!(MININT.checked_sub(MININT), Some(ZERO));
+    assert_eq!(ZERO.checked_sub(MININT), Some(-MININT));
+    assert_eq!(MAX.checked_sub(MIN), None);
+    assert_eq!(MIN.checked_sub(MAX), None);
+    assert_eq!(NAN.checked_sub(NAN), None);
+}
+
+#[test]
+fn mul() {
+    assert_eq!(TWO.checked_mul(THREE), Some(SIX));
+    assert_eq!(ZERO.checked_mul(MAX), Some(ZERO));
+    assert_eq!(TINIEST.checked_mul(ZIP), Some(ZERO));
+    assert_eq!(MAX.checked_mul(TWO), None);
+    assert_eq!(TINIEST.checked_mul(TENTH), None);
+    assert_eq!(TINIEST.checked_mul(TINIEST), None);
+    assert_eq!(NAN.checked_mul(ZERO), None);
+}
+
+#[test]
+fn div() {
+    assert_eq!(SIX.checked_div(TWO), Some(THREE));
+    assert_eq!(ZERO.checked_div(TWO), Some(ZERO));
+    assert_eq!(ONE.checked_div(ZERO), None);
+    assert_eq!(ZERO.checked_div(ZERO), None);
+    assert_eq!(ONE.checked_div(ZIP), None);
+    assert_eq!(MAX.checked_div(TENTH), None);
+    assert_eq!(TINIEST.checked_div(TEN), None);
+    assert_eq!(NAN.checked_div(ONE), None);
+    assert_eq!(
+        MININT.checked_div(NEGATIVE_ONE),
+        Some(Dec64::from_parts(-MIN_COEFFICIENT / 10 + 1, 1))
+    );
+}
+
+#[test]
+fn neg_abs() {
+    assert_eq!(ONE.checked_neg(), Some(NEGATIVE_ONE));
+    assert_eq!(ZERO.checked_neg(), Some(ZERO));
+    assert_eq!(MAX.checked_neg(), Some(-MAX));
+    assert_eq!(MIN.checked_neg(), None);
+    assert_eq!(NAN.checked_neg(), None);
+
+    assert_eq!(NEGATIVE_ONE.checked_abs(), Some(ONE));
+    assert_eq!(MININT.checked_abs(), Some(MAXINT + ONE));
+    assert_eq!(MIN.checked_abs(), None);
+    assert_eq!(NONNORMAL_NAN.checked_abs(), None);
+}
+
+#[test]
+fn rem() {
+    assert_eq!(SEVEN.checked_rem(TWO), Some(ONE));
+    assert_eq!(
+        Dec64::from_parts(-75, -1).checked_rem(TWO),
+        Some(Dec64::from_parts(-15, -1))
+    );
+    assert_eq!(MAX.checked_rem(TINIEST), Some(ZERO));
+    assert_eq!(ONE.checked_rem(ZERO), None);
+    assert_eq!(NAN.checked_rem(ONE), None);
+    assert_eq!(ONE.checked_rem(NAN), None);
+}
@@ -0,0 +1,130 @@
+#![allow(clippy::wildcard_imports)]
+
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashSet};
+
+use dec64::consts::*;
+use dec64::*;
+
+#[test]
+fn order() {
+    assert!(ONE < TWO);
+    assert!(NEGATIVE_ONE < ZERO);
+    assert!(ZERO < TINIEST);
+    assert!(NEGATIVE_TINIEST < ZERO);
+    assert!(MIN < MININT);
+    assert!(MAXINT < MAX);
+    assert!(MIN < MAX);
+    assert!(HALF < ALMOST_ONE);
+    assert!(ALMOST_ONE < ONE);
+    assert!(NEGATIVE_PI < NEGATIVE_THREE);
+    assert_eq!(ONE.cmp(&Dec64::from_parts(10, -1)), Ordering::Equal);
+    assert_eq!(ZERO.cmp(&ZIP), Ordering::Equal);
+}
+
+#[test]
+fn neighbors() {
+    let below_max = Dec64::from_parts(MAX_COEFFICIENT - 1, MAX_EXP);
+    let above_min = Dec64::from_parts(MIN_COEFFICIENT + 1, MAX_EXP);
+    assert!(below_max < MAX);
+    assert!(MAX > below_max);
+    assert!(MIN < above_min);
+    assert!(above_min > MIN);
+    assert!(-MAX < MAX);
+    assert!(MAX > -MAX);
+    assert!(MIN < -MAX);
+    assert!(-MAX > MIN);
+    assert!(ZERO < TINIEST);
+    assert!(TINIEST < Dec64::from_parts(2, MIN_EXP));
+    assert!(NEGATIVE_TINIEST < ZERO);
+    assert!(Dec64::from_parts(-2, MIN_EXP) < NEGATIVE_TINIEST);
+    assert!(TINIEST < MAX);
+    assert!(MIN < NEGATIVE_TINIEST);
+    assert!(MAXINT < Dec64::from_parts(3602879701896397, 1));
+    assert!(Dec64::from_parts(3602879701896396, 1) < MAXINT);
+    assert_eq!(
+        Dec64::from_parts(3602879701896396, 1),
+        Dec64::from_parts(36028797018963960, 0)
+    );
+    assert!(Dec64::from_parts(1, 17) > Dec64::from_parts(MAX_COEFFICIENT, 0));
+    assert!(Dec64::from_parts(-1, 17) < Dec64::from_parts(MIN_COEFFICIENT, 0));
+}
+
+#[test]
+fn consistent_with_subtraction() {
+    // xorshift64 so that the test is deterministic
+    let mut state = 0x9e37_79b9_7f4a_7c15_u64;
+    let mut next = || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        // Keep exponents close together so that the values interact.
+        Dec64::from_parts((state as i64) >> 12, ((state >> 56) as i8) % 20)
+    };
+    for _ in 0..100_000 {
+        let (a, b) = (next(), next());
+        let difference = a - b;
+        if difference.is_nan() {
+            continue;
+        }
+        assert_eq!(a.cmp(&b), difference.sign().cmp(&0), "{:?} {:?}", a, b);
+        assert_eq!(b.cmp(&a), a.cmp(&b).reverse(), "{:?} {:?}", a, b);
+        assert_eq!(a == b, difference.is_zero(), "{:?} {:?}", a, b);
+    }
+}
+
+#[test]
+fn nan_order() {
+    assert!(MAX < NAN);
+    assert!(MIN < NAN);
+    assert!(ZERO < NAN);
+    assert!(NAN < NONNORMAL_NAN);
+    assert_eq!(NAN.cmp(&NAN), Ordering::Equal);
+    assert_eq!(NAN.partial_cmp(&NAN), Some(Ordering::Equal));
+    assert_ne!(NAN.cmp(&NONNORMAL_NAN), Ordering::Equal);
+}
+
+#[test]
+fn sort() {
+    let mut values = vec![NAN, MAX, ONE, ZIP, NEGATIVE_ONE, MIN, HALF, TINIEST, ZERO];
+    values.sort();
+    assert_eq!(
+        values,
+        vec![MIN, NEGATIVE_ONE, ZIP, ZERO, TINIEST, HALF, ONE, MAX, NAN]
+    );
+}
+
+#[test]
+fn hash_consistent_with_eq() {
+    let mut set = HashSet::new();
+    set.insert(ONE);
+    assert!(set.contains(&Dec64::from_parts(10, -1)));
+    assert!(set.contains(&Dec64::from_parts(10000000000000000, -16)));
+    assert!(!set.contains(&TWO));
+
+    set.insert(ZERO);
+    assert!(set.contains(&ZIP));
+    assert!(set.contains(&Dec64::from_parts(0, -127)));
+
+    set.insert(NAN);
+    assert!(set.contains(&NAN));
+    assert!(!set.contains(&NONNORMAL_NAN));
+
+    set.insert(TEN);
+    assert!(set.contains(&Dec64::from_parts(10, 0)));
+    assert_eq!(set.len(), 4);
+}
+
+#[test]
+fn btree_map() {
+    let mut book = BTreeMap::new();
+    book.insert(Dec64::from_parts(1050, -2), "a");
+    book.insert(Dec64::from_parts(105, -1), "b");
+    book.insert(Dec64::from_parts(999, -2), "c");
+    assert_eq!(book.len(), 2);
+    assert_eq!(
+        book.first_key_value(),
+        Some((&Dec64::from_parts(999, -2), &"c"))
+    );
+    assert_eq!(book[&Dec64::from_parts(105, -1)], "b");
+}
@@ -0,0 +1,152 @@
+#![allow(clippy::wildcard_imports)]
+
+use dec64::consts::*;
+use dec64::*;
+
+#[test]
+fn sticky_flags() {
+    let mut context = Context::default();
+    assert_eq!(context.add(ONE, TWO), Ok(THREE));
+    assert_eq!(context.mul(CENT, CENT), Ok(Dec64::from_parts(1, -4)));
+    assert!(context.flags().is_empty());
+
+    assert_eq!(context.div(TWO, THREE), Ok(TWO / THREE));
+    assert_eq!(context.flags(), Flags::INEXACT);
+    // Exact operations don’t lower flags.
+    assert_eq!(context.sub(THREE, ONE), Ok(TWO));
+    assert_eq!(context.flags(), Flags::INEXACT);
+
+    assert!(context.mul(MAX, TEN).unwrap().is_nan());
+    assert_eq!(context.flags(), Flags::INEXACT | Flags::OVERFLOW);
+    // NaN operands don’t signal anything.
+    assert!(context.add(NAN, ONE).unwrap().is_nan());
+    assert_eq!(context.flags(), Flags::INEXACT | Flags::OVERFLOW);
+
+    context.clear_flags();
+    assert!(context.flags().is_empty());
+}
+
+#[test]
+fn conditions() {
+    let flags = |f: fn(&mut Context) -> Result<Dec64, Trapped>| {
+        let mut context = Context::default();
+        f(&mut context).unwrap();
+        context.flags()
+    };
+
+    assert_eq!(flags(|c| c.new_dec64(1, 0)), Flags::empty());
+    assert_eq!(flags(|c| c.new_dec64(i64::MAX, 0)), Flags::INEXACT);
+    assert_eq!(flags(|c| c.new_dec64(10, -128)), Flags::empty());
+    assert_eq!(
+        flags(|c| c.new_dec64(1, -128)),
+        Flags::INEXACT | Flags::UNDERFLOW
+    );
+    assert_eq!(
+        flags(|c| c.new_dec64(1, 200)),
+        Flags::INEXACT | Flags::OVERFLOW
+    );
+
+    assert_eq!(flags(|c| c.add(ONE, TINIEST)), Flags::INEXACT);
+    assert_eq!(flags(|c| c.add(MAX, MAX)), Flags::INEXACT | Flags::OVERFLOW);
+    assert_eq!(flags(|c| c.sub(MIN, MAX)), Flags::INEXACT | Flags::OVERFLOW);
+    assert_eq!(flags(|c| c.sub(MIN, MIN)), Flags::empty());
+
+    assert_eq!(flags(|c| c.mul(TINIEST, TEN)), Flags::empty());
+    assert_eq!(
+        flags(|c| c.mul(TINIEST, TENTH)),
+        Flags::INEXACT | Flags::UNDERFLOW
+    );
+    assert_eq!(
+        flags(|c| c.mul(Dec64::from_parts(15, -127), TENTH)),
+        Flags::INEXACT | Flags::UNDERFLOW
+    );
+
+    assert_eq!(flags(|c| c.div(ONE, ZERO)), Flags::DIVISION_BY_ZERO);
+    assert_eq!(flags(|c| c.div(ZERO, ZERO)), Flags::INVALID);
+    assert_eq!(flags(|c| c.div(NAN, ZERO)), Flags::empty());
+    assert_eq!(flags(|c| c.div(ZERO, ONE)), Flags::empty());
+    assert_eq!(
+        flags(|c| c.div(TINIEST, TEN)),
+        Flags::INEXACT | Flags::UNDERFLOW
+    );
+
+    assert_eq!(flags(|c| c.round_to_places(PI, -2)), Flags::INEXACT);
+    assert_eq!(flags(|c| c.round_to_places(CENT, -2)), Flags::empty());
+}
+
+#[test]
+fn rounding() {
+    let mut context = Context::new(RoundingMode::Floor);
+    assert_eq!(
+        context.div(NEGATIVE_ONE, THREE),
+        Ok(Dec64::from_parts(-33333333333333334, -17))
+    );
+    assert_eq!(
+        context.round_to_places(PI, -2),
+        Ok(Dec64::from_parts(314, -2))
+    );
+    assert_eq!(context.new_dec64(-1, -128), Ok(NEGATIVE_TINIEST));
+
+    context.rounding = RoundingMode::HalfEven;
+    assert_eq!(
+        context.round_to_places(Dec64::from_parts(25, -1), 0),
+        Ok(TWO)
+    );
+}
+
+#[test]
+fn traps() {
+    let mut context = Context::default();
+    context.traps = Flags::DIVISION_BY_ZERO | Flags::OVERFLOW;
+    assert_eq!(context.div(ONE, THREE), Ok(ONE / THREE));
+    assert_eq!(
+        context.div(ONE, ZERO),
+        Err(Trapped {
+            flags: Flags::DIVISION_BY_ZERO,
+            result: NAN
+        })
+    );
+    let error = context.mul(MAX, MAX).unwrap_err();
+    assert_eq!(error.flags, Flags::OVERFLOW);
+    // Trapped flags are raised nonetheless.
+    assert_eq!(
+        context.flags(),
+        Flags::INEXACT | Flags::OVERFLOW | Flags::DIVISION_BY_ZERO
+    );
+
+    context.traps = Flags::INEXACT;
+    let error = context.div(TWO, THREE).unwrap_err();
+    assert_eq!(error.result, TWO / THREE);
+    assert_eq!(context.mul(TWO, THREE), Ok(SIX));
+}
+
+#[test]
+fn flags() {
+    let flags = Flags::INEXACT | Flags::UNDERFLOW;
+    assert!(flags.contains(Flags::INEXACT));
+    assert!(!flags.contains(Flags::INEXACT | Flags::INVALID));
+    assert!(flags.intersects(Flags::INEXACT | Flags::INVALID));
+    assert!(!flags.intersects(!flags));
+    assert!(Flags::all().contains(flags));
+    assert_eq!(!Flags::all(), Flags::empty());
+    assert_eq!(Flags::default(), Flags::empty());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn display() {
+    assert_eq!(Flags::empty().to_string(), "none");
+    assert_eq!(
+        (Flags::INEXACT | Flags::DIVISION_BY_ZERO).to_string(),
+        "inexact, division by zero"
+    );
+    assert_eq!(
+        format!("{:?}", Flags::OVERFLOW | Flags::INEXACT),
+        r#"{"inexact", "overflow"}"#
+    );
+    let error = Trapped {
+        flags: Flags::INVALID,
+        result: NAN,
+    };
+    assert_eq!(error.to_string(), "DEC64 operation signalled invalid");
+}
@@ -0,0 +1,118 @@
+#![allow(clippy::wildcard_imports)]
+
+use dec64::consts::*;
+use dec64::*;
+
+#[test]
+fn new() {
+    assert_eq!(Dec64::new_exact(0, 1000), Some(ZERO));
+    assert_eq!(
+        Dec64::new_exact(12345, -2),
+        Some(Dec64::from_parts(12345, -2))
+    );
+    assert_eq!(Dec64::new_exact(MAX_COEFFICIENT, 0), Some(MAXINT));
+    assert_eq!(
+        Dec64::new_exact(MAX_COEFFICIENT * 10, 0),
+        Some(Dec64::from_parts(MAX_COEFFICIENT, 1))
+    );
+    assert_eq!(Dec64::new_exact(MAX_COEFFICIENT + 1, 0), None);
+    assert_eq!(Dec64::new_exact(1000, -130), Some(TINIEST));
+    assert_eq!(Dec64::new_exact(1001, -130), None);
+    assert_eq!(Dec64::new_exact(1, -128), None);
+    assert_eq!(
+        Dec64::new_exact(1, 140),
+        Some(Dec64::from_parts(10_000_000_000_000, 127))
+    );
+    assert_eq!(Dec64::new_exact(1, 144), None);
+}
+
+#[test]
+fn add_sub() {
+    assert_eq!(CENT.add_exact(CENT), Some(Dec64::from_parts(2, -2)));
+    assert_eq!(
+        ONE.add_exact(EPSILON),
+        Some(Dec64::from_parts(10000000000000001, -16))
+    );
+    assert_eq!(TEN.add_exact(EPSILON), None);
+    assert_eq!(MAXINT.add_exact(ONE), None);
+    assert_eq!(
+        MAXINT.add_exact(Dec64::from_parts(3, 0)),
+        Some(Dec64::from_parts(3602879701896397, 1))
+    );
+    assert_eq!(MAX.add_exact(TINIEST), None);
+    assert_eq!(MAX.add_exact(MAX), None);
+    assert_eq!(MAX.add_exact(ZERO), Some(MAX));
+    assert_eq!(NAN.add_exact(ZERO), None);
+
+    assert_eq!(ONE.sub_exact(CENT), Some(Dec64::from_parts(99, -2)));
+    assert_eq!(MIN.sub_exact(MIN), Some(ZERO));
+    assert_eq!(ZERO.sub_exact(MININT), None);
+    assert_eq!(ONE.sub_exact(TINIEST), None);
+    assert_eq!(ONE.sub_exact(NAN), None);
+}
+
+#[test]
+fn mul() {
+    assert_eq!(CENT.mul_exact(CENT), Some(Dec64::from_parts(1, -4)));
+    assert_eq!(
+        Dec64::from_parts(1999, -2).mul_exact(THREE),
+        Some(Dec64::from_parts(5997, -2))
+    );
+    assert_eq!(
+        MAXINT.mul_exact(TEN),
+        Some(Dec64::from_parts(MAX_COEFFICIENT, 1))
+    );
+    assert_eq!(MAXINT.mul_exact(TWO), None);
+    assert_eq!(PI.mul_exact(PI), None);
+    assert_eq!(TINIEST.mul_exact(TENTH), None);
+    assert_eq!(MAX.mul_exact(TEN), None);
+    assert_eq!(ZERO.mul_exact(MAX), Some(ZERO));
+    assert_eq!(NAN.mul_exact(ONE), None);
+}
+
+#[test]
+fn div() {
+    assert_eq!(ONE.div_exact(FOUR), Some(Dec64::from_parts(25, -2)));
+    assert_eq!(
+        Dec64::from_parts(100, 0).div_exact(FOUR),
+        Some(Dec64::from_parts(25, 0))
+    );
+    assert_eq!(ONE.div_exact(THREE), None);
+    assert_eq!(TWO.div_exact(SEVEN), None);
+    assert_eq!(ONE.div_exact(ZERO), None);
+    assert_eq!(ZERO.div_exact(ONE), Some(ZERO));
+    assert_eq!(TINIEST.div_exact(TEN), None);
+    assert_eq!(MAX.div_exact(TENTH), None);
+    assert_eq!(MININT.div_exact(NEGATIVE_ONE), None);
+    assert_eq!(
+        MININT.div_exact(NEGATIVE_TWO),
+        Some(Dec64::from_parts(-MIN_COEFFICIENT / 2, 0))
+    );
+    assert_eq!(NAN.div_exact(ONE), None);
+}
+
+#[test]
+fn consistent_with_operators() {
+    // xorshift64 so that the test is deterministic
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    let mut next = || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        // Small coefficients so that many results are exact.
+        Dec64::from_parts((state as i64) >> 40, ((state >> 56) as i8) % 10)
+    };
+    for _ in 0..100_000 {
+        let (a, b) = (next(), next());
+        for (exact, result) in [
+            (a.add_exact(b), a + b),
+            (a.sub_exact(b), a - b),
+            (a.mul_exact(b), a * b),
+            (a.div_exact(b), a / b),
+        ] {
+            if let Some(exact) = exact {
+                assert_eq!(exact, result, "{:?} {:?}", a, b);
+            }
+        }
+    }
+}
@@ -0,0 +1,130 @@
+#![allow(clippy::wildcard_imports)]
+
+use dec64::consts::*;
+use dec64::*;
+
+#[test]
+fn exp() {
+    assert_eq!(ZERO.exp(), ONE);
+    assert_eq!(ONE.exp(), E);
+    assert_eq!(TEN.exp(), Dec64::from_parts(22026465794806717, -12));
+    assert_eq!(NEGATIVE_ONE.exp(), Dec64::from_parts(3678794411714423, -16));
+    assert_eq!(HALF.exp(), Dec64::from_parts(16487212707001281, -16));
+    assert_eq!(LN_10.exp(), TEN);
+    assert_eq!(
+        Dec64::from(330).exp(),
+        Dec64::from_parts(20757690299227870, 127)
+    );
+    assert_eq!(Dec64::from(-293).exp(), TINIEST);
+}
+
+#[test]
+fn exp_special() {
+    assert!(Dec64::from(331).exp().is_nan());
+    assert!(Dec64::from_parts(9999, -1).exp().is_nan());
+    assert!(Dec64::from(1000).exp().is_nan());
+    assert!(MAX.exp().is_nan());
+    assert_eq!(Dec64::from(-300).exp(), ZERO);
+    assert_eq!(Dec64::from_parts(-9999, -1).exp(), ZERO);
+    assert_eq!(Dec64::from(-1000).exp(), ZERO);
+    assert_eq!(MIN.exp(), ZERO);
+    assert!(NAN.exp().is_nan());
+}
+
+#[test]
+fn exp_m1() {
+    assert_eq!(ZERO.exp_m1(), ZERO);
+    assert_eq!(
+        Dec64::from_parts(1, -10).exp_m1(),
+        Dec64::from_parts(100000000005, -21)
+    );
+    assert_eq!(TINIEST.exp_m1(), TINIEST);
+    assert_eq!(HALF.exp_m1(), HALF.exp() - ONE);
+    assert_eq!((-HALF).exp_m1(), Dec64::from_parts(-3934693402873666, -16));
+    assert_eq!(Dec64::from(-1000).exp_m1(), NEGATIVE_ONE);
+    assert_eq!(MIN.exp_m1(), NEGATIVE_ONE);
+    assert!(MAX.exp_m1().is_nan());
+    assert!(NAN.exp_m1().is_nan());
+}
+
+#[test]
+fn ln() {
+    assert_eq!(ONE.ln(), ZERO);
+    assert_eq!(E.ln(), ONE);
+    assert_eq!(TWO.ln(), LN_2);
+    assert_eq!(TEN.ln(), LN_10);
+    assert_eq!(HALF.ln(), -LN_2);
+    assert_eq!(MAX.ln(), Dec64::from_parts(33055140174104079, -14));
+    assert_eq!(TINIEST.ln(), Dec64::from_parts(-29242830681024380, -14));
+    assert_eq!(
+        Dec64::from_parts(9999999999999999, -16).ln(),
+        Dec64::from_parts(-10000000000000001, -32)
+    );
+    assert!(ZERO.ln().is_nan());
+    assert!(NEGATIVE_ONE.ln().is_nan());
+    assert!(NAN.ln().is_nan());
+}
+
+#[test]
+fn ln_1p() {
+    assert_eq!(ZERO.ln_1p(), ZERO);
+    assert_eq!(ONE.ln_1p(), LN_2);
+    assert_eq!((-HALF).ln_1p(), -LN_2);
+    assert_eq!(
+        Dec64::from_parts(1, -10).ln_1p(),
+        Dec64::from_parts(99999999995, -21)
+    );
+    assert_eq!(TINIEST.ln_1p(), TINIEST);
+    assert_eq!(NEGATIVE_TINIEST.ln_1p(), NEGATIVE_TINIEST);
+    assert!(NEGATIVE_ONE.ln_1p().is_nan());
+    assert!(NEGATIVE_TWO.ln_1p().is_nan());
+    assert!(NAN.ln_1p().is_nan());
+}
+
+#[test]
+fn log10() {
+    assert_eq!(ONE.log10(), ZERO);
+    assert_eq!(Dec64::from(1000).log10(), THREE);
+    assert_eq!(Dec64::from(1000).log10().coefficient(), 3);
+    assert_eq!(Dec64::from_parts(1000, -5).log10(), NEGATIVE_TWO);
+    assert_eq!(GOOGOL.log10(), Dec64::from(100));
+    assert_eq!(TINIEST.log10(), Dec64::from(-127));
+    assert_eq!(TWO.log10(), Dec64::from_parts(3010299956639812, -16));
+    assert_eq!(FIVE.log10(), Dec64::from_parts(6989700043360188, -16));
+    assert_eq!(E.log10(), LOG10_E);
+    assert!(ZERO.log10().is_nan());
+    assert!(NEGATIVE_TEN.log10().is_nan());
+    assert!(NAN.log10().is_nan());
+}
+
+#[test]
+fn log2() {
+    assert_eq!(ONE.log2(), ZERO);
+    assert_eq!(Dec64::from(1024).log2(), TEN);
+    assert_eq!(Dec64::from(1024).log2().coefficient(), 10);
+    assert_eq!(Dec64::from(1u64 << 54).log2(), Dec64::from(54));
+    assert_eq!(Dec64::from_parts(125, -3).log2(), NEGATIVE_THREE);
+    assert_eq!(Dec64::from_parts(9765625, -10).log2(), NEGATIVE_TEN);
+    assert_eq!(TEN.log2(), Dec64::from_parts(33219280948873623, -16));
+    assert_eq!(THREE.log2(), Dec64::from_parts(15849625007211562, -16));
+    assert_eq!(E.log2(), LOG2_E);
+    assert!(ZERO.log2().is_nan());
+    assert!(NEGATIVE_TWO.log2().is_nan());
+    assert!(NAN.log2().is_nan());
+}
+
+#[test]
+fn log() {
+    assert_eq!(Dec64::from(81).log(THREE), FOUR);
+    assert_eq!(EIGHT.log(HALF), NEGATIVE_THREE);
+    assert_eq!(Dec64::from(1000).log(TEN).coefficient(), 3);
+    assert_eq!(Dec64::from(1024).log(TWO).coefficient(), 10);
+    assert_eq!(TEN.log(THREE), Dec64::from_parts(20959032742893846, -16));
+    assert_eq!(ONE.log(SEVEN), ZERO);
+    assert!(TEN.log(ONE).is_nan());
+    assert!(TEN.log(ZERO).is_nan());
+    assert!(TEN.log(NEGATIVE_TWO).is_nan());
+    assert!(ZERO.log(TEN).is_nan());
+    assert!(TEN.log(NAN).is_nan());
+    assert!(NAN.log(TEN).is_nan());
+}
@@ -0,0 +1,156 @@
+#![allow(clippy::wildcard_imports)]
+
+use dec64::consts::*;
+use dec64::*;
+
+#[test]
+fn shortest() {
+    assert_eq!(Dec64::from(0.1), TENTH);
+    assert_eq!(Dec64::from(-0.1), NEGATIVE_TENTH);
+    assert_eq!(Dec64::from(0.3), Dec64::from_parts(3, -1));
+    assert_eq!(
+        Dec64::from(0.1 + 0.2),
+        Dec64::from_parts(30000000000000004, -17)
+    );
+    assert_eq!(Dec64::from(1e23), Dec64::from_parts(1, 23));
+    assert_eq!(Dec64::from(5e-324), ZERO);
+    assert_eq!(Dec64::from(0.0), ZERO);
+    assert_eq!(Dec64::from(-0.0), ZERO);
+    assert_eq!(Dec64::from(1e-127), TINIEST);
+    assert_eq!(Dec64::from(1e140), Dec64::from_parts(10000000000000, 127));
+}
+
+#[test]
+fn rounded() {
+    // The shortest decimals have 17 digits and don’t fit into the coefficient.
+    assert_eq!(
+        Dec64::from(0.48061854646744073),
+        Dec64::from_parts(4806185464674407, -16)
+    );
+    // Rounding the shortest decimal instead of the exact value would round up.
+    assert_eq!(
+        Dec64::from(0.45631575206454095),
+        Dec64::from_parts(4563157520645409, -16)
+    );
+    assert_eq!(
+        Dec64::from(2f64.powi(200)),
+        Dec64::from_parts(16069380442589903, 44)
+    );
+    // Exactly halfway between two DEC64 numbers rounds away from zero.
+    assert_eq!(
+        Dec64::from(4503599627370494.5),
+        Dec64::from_parts(4503599627370495, 0)
+    );
+    assert_eq!(
+        Dec64::from(-4503599627370495.5),
+        Dec64::from_parts(-4503599627370496, 0)
+    );
+    // The shortest decimal has too many digits at the smallest exponent.
+    assert_eq!(Dec64::from(1.23e-127), TINIEST);
+    assert_eq!(
+        Dec64::from(1.2345678e-121),
+        Dec64::from_parts(1234568, -127)
+    );
+    // The exact value is slightly above 0.5 × 10^-127, while its shortest decimal is exactly that.
+    assert_eq!(Dec64::from(5e-128), TINIEST);
+    assert_eq!(Dec64::from(4.9e-128), ZERO);
+}
+
+#[test]
+fn shortest_f32() {
+    assert_eq!(Dec64::from(0.1f32), TENTH);
+    assert_eq!(Dec64::from(-0.3f32), Dec64::from_parts(-3, -1));
+    assert_eq!(Dec64::from(f32::MAX), Dec64::from_parts(34028235, 31));
+    assert_eq!(
+        Dec64::from(f32::MIN_POSITIVE),
+        Dec64::from_parts(11754944, -45)
+    );
+    assert_eq!(Dec64::from(1e-45f32), Dec64::from_parts(1, -45));
+    assert_eq!(Dec64::from(16777217f32), Dec64::from_parts(16777216, 0));
+}
+
+#[test]
+fn try_from() {
+    assert_eq!(Dec64::try_from_f64(0.1), Ok(TENTH));
+    assert_eq!(Dec64::try_from_f32(-0.5), Ok(-HALF));
+    assert_eq!(Dec64::try_from_f64(f64::NAN), Err(TryFromFloatError::Nan));
+    assert_eq!(Dec64::try_from_f32(f32::NAN), Err(TryFromFloatError::Nan));
+    assert_eq!(
+        Dec64::try_from_f64(f64::INFINITY),
+        Err(TryFromFloatError::Infinite)
+    );
+    assert_eq!(
+        Dec64::try_from_f32(f32::NEG_INFINITY),
+        Err(TryFromFloatError::Infinite)
+    );
+    assert_eq!(
+        Dec64::try_from_f64(-1e144),
+        Err(TryFromFloatError::OutOfRange)
+    );
+    assert_eq!(Dec64::try_from_f64(1e-200), Ok(ZERO));
+    assert_eq!(
+        TryFromFloatError::Infinite.to_string(),
+        "cannot convert infinite float to DEC64"
+    );
+}
+
+#[test]
+fn out_of_range() {
+    assert!(Dec64::from(f64::MAX).is_nan());
+    assert!(Dec64::from(-4e143).is_nan());
+    assert!(Dec64::from(f64::INFINITY).is_nan());
+    assert!(Dec64::from(f64::NEG_INFINITY).is_nan());
+    assert!(Dec64::from(f64::NAN).is_nan());
+    assert!(Dec64::from(f32::INFINITY).is_nan());
+    assert!(Dec64::from(f32::NAN).is_nan());
+}
+
+#[test]
+fn random_round_trip() {
+    // Xorshift, to sample floats whose digits are all above the smallest exponent of DEC64.
+    let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+    let mut round_trips = 0;
+    for _ in 0..100_000 {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        let exponent = 1023 - 330 + (state >> 52) % 660;
+        let float = f64::from_bits((state & 0x800f_ffff_ffff_ffff) | exponent << 52);
+
+        let dec = Dec64::from(float);
+        let back = f64::from(dec);
+        if back == float {
+            round_trips += 1;
+        } else {
+            // Only the shortest decimals with 17 digits can be too long for the coefficient.
+            assert!(
+                dec.coefficient().unsigned_abs() >= 10u64.pow(15),
+                "{float:e} became {dec}"
+            );
+            assert!(
+                ((back - float) / float).abs() < 1e-15,
+                "{float:e} became {dec}"
+            );
+        }
+    }
+    // Most shortest decimals have at most 16 digits, or start with a small digit.
+    assert!(round_trips > 90_000);
+}
+
+#[test]
+fn random_round_trip_f32() {
+    // The shortest decimal of an f32 always fits, so all of them round-trip.
+    let mut state: u32 = 0x9e37_79b9;
+    for _ in 0..100_000 {
+        state ^= state << 13;
+        state ^= state >> 17;
+        state ^= state << 5;
+        let float = f32::from_bits(state);
+        if !float.is_finite() {
+            continue;
+        }
+        let dec = Dec64::from(float);
+        assert_eq!(f32::from(dec), float, "{float:e} became {dec}");
+        assert_eq!(dec, format!("{float:e}").parse::<Dec64>().unwrap());
+    }
+}
@@ -0,0 +1,84 @@
+#![allow(clippy::wildcard_imports)]
+
+use dec64::consts::*;
+use dec64::*;
+
+#[test]
+fn small() {
+    assert_eq!(Dec64::from(0u64), ZERO);
+    assert_eq!(Dec64::from(-5i64), NEGATIVE_FIVE);
+    assert_eq!(Dec64::from(MAX_COEFFICIENT), MAXINT);
+    assert_eq!(Dec64::from(MIN_COEFFICIENT as i128), MININT);
+    assert_eq!(Dec64::from_i64_exact(MAX_COEFFICIENT), Some(MAXINT));
+    assert_eq!(Dec64::from_i128_exact(-1), Some(NEGATIVE_ONE));
+    assert_eq!(Dec64::from_usize_exact(10), Some(TEN));
+    assert_eq!(Dec64::from_u128_exact(0), Some(ZERO));
+}
+
+#[test]
+fn rounded_once() {
+    // Rounding to 19 digits first would produce a tie, and rounding that tie would then go up.
+    assert_eq!(
+        Dec64::from(36028797018963974995i128),
+        Dec64::from_parts(3602879701896397, 4)
+    );
+    assert_eq!(
+        Dec64::from(36028797018963975000u128),
+        Dec64::from_parts(3602879701896398, 4)
+    );
+    assert_eq!(
+        Dec64::from(-36028797018963974995i128),
+        Dec64::from_parts(-3602879701896397, 4)
+    );
+    assert_eq!(
+        Dec64::from(i64::MAX),
+        Dec64::from_parts(9223372036854776, 3)
+    );
+    assert_eq!(
+        Dec64::from(i64::MIN),
+        Dec64::from_parts(-9223372036854776, 3)
+    );
+    assert_eq!(
+        Dec64::from(u64::MAX),
+        Dec64::from_parts(18446744073709552, 3)
+    );
+    assert_eq!(
+        Dec64::from(i128::MAX),
+        Dec64::from_parts(17014118346046923, 22)
+    );
+    assert_eq!(
+        Dec64::from(i128::MIN),
+        Dec64::from_parts(-17014118346046923, 22)
+    );
+    assert_eq!(
+        Dec64::from(u128::MAX),
+        Dec64::from_parts(34028236692093846, 22)
+    );
+}
+
+#[test]
+fn exact() {
+    assert_eq!(Dec64::from_i64_exact(MAX_COEFFICIENT + 1), None);
+    assert_eq!(
+        Dec64::from_i64_exact(MAX_COEFFICIENT * 10),
+        Some(Dec64::from_parts(MAX_COEFFICIENT, 1))
+    );
+    assert_eq!(Dec64::from_i64_exact((MAX_COEFFICIENT + 1) * 10), None);
+    assert_eq!(Dec64::from_i64_exact(i64::MAX), None);
+    assert_eq!(
+        Dec64::from_i64_exact(9_000_000_000_000_000_000),
+        Some(Dec64::from_parts(9, 18))
+    );
+    assert_eq!(Dec64::from_u64_exact(u64::MAX), None);
+    assert_eq!(
+        Dec64::from_u128_exact(10u128.pow(38)),
+        Some(Dec64::from_parts(1, 38))
+    );
+    assert_eq!(Dec64::from_u128_exact(10u128.pow(38) + 1), None);
+    assert_eq!(Dec64::from_i128_exact(i128::MIN), None);
+    assert_eq!(
+        Dec64::from_i128_exact(-12345 * 10i128.pow(30)),
+        Some(Dec64::from_parts(-12345, 30))
+    );
+    assert_eq!(Dec64::from_isize_exact(isize::MAX), None);
+}
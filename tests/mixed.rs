@@ -0,0 +1,95 @@
+#![allow(clippy::wildcard_imports)]
+
+use dec64::consts::*;
+use dec64::*;
+
+#[test]
+fn integer_operators() {
+    let price = Dec64::from_parts(1999, -2);
+    assert_eq!(price * 3, Dec64::from_parts(5997, -2));
+    assert_eq!(3u8 * price, Dec64::from_parts(5997, -2));
+    assert_eq!(price + 1, Dec64::from_parts(2099, -2));
+    assert_eq!(1i64 + price, Dec64::from_parts(2099, -2));
+    assert_eq!(price - 20, -CENT);
+    assert_eq!(20usize - price, CENT);
+    assert_eq!(price / 2, Dec64::from_parts(9995, -3));
+    assert_eq!(1 / FOUR, Dec64::from_parts(25, -2));
+
+    // Fast path with exponent zero.
+    assert_eq!(TWO + 3, FIVE);
+    assert_eq!(TWO - 3i128, NEGATIVE_ONE);
+    assert_eq!(3u32 - TWO, ONE);
+    assert_eq!(SIX * -7i16, Dec64::from(-42));
+    assert_eq!(MAXINT + 0, MAXINT);
+    assert_eq!(MAXINT + 1, MAXINT + ONE);
+    assert_eq!(MININT - 1u64, MININT - ONE);
+    assert_eq!(MAXINT * 2, MAXINT * TWO);
+    assert_eq!(ZERO * -5, ZERO);
+    assert!((NAN + 1i32).is_nan());
+    assert!((NAN * 1u8).is_nan());
+    assert!((1i32 - NAN).is_nan());
+
+    // Large integers are converted.
+    assert_eq!(ONE + i64::MAX, Dec64::from(i64::MAX));
+    assert_eq!(u128::MAX * ONE, Dec64::from(u128::MAX));
+    assert_eq!(i128::MIN - ONE, Dec64::from(i128::MIN));
+}
+
+#[test]
+fn float_operators() {
+    assert_eq!(TENTH + 0.2, Dec64::from_parts(3, -1));
+    assert_eq!(0.5f32 * TEN, FIVE);
+    assert_eq!(ONE - 0.25, Dec64::from_parts(75, -2));
+    assert_eq!(1.5 / THREE, HALF);
+}
+
+#[test]
+fn integer_comparisons() {
+    assert!(ONE > 0);
+    assert!(0 < ONE);
+    assert!(NEGATIVE_ONE < 0u8);
+    assert!(HALF < 1);
+    assert!(HALF > 0);
+    assert!(-HALF > -1);
+    assert!(-HALF < 0i128);
+    assert_eq!(Dec64::from_parts(10, -1), 1);
+    assert_eq!(1, Dec64::from_parts(10, -1));
+    assert_ne!(Dec64::from_parts(11, -1), 1);
+    assert_eq!(ZERO, 0);
+    assert_eq!(Dec64::from_parts(0, 5), 0usize);
+    assert_eq!(Dec64::from_parts(3, 2), 300);
+    assert!(Dec64::from_parts(3, 2) > 299);
+    assert!(Dec64::from_parts(3, 2) < 301u16);
+    assert!(Dec64::from_parts(-3, 2) < -299);
+
+    // Comparisons are exact, even where conversion would round.
+    assert!(Dec64::from(u128::MAX) != u128::MAX);
+    assert!(Dec64::from(u128::MAX) < u128::MAX);
+    assert!(Dec64::from(i64::MAX) > i64::MAX);
+    assert!(Dec64::from(i64::MAX - 1000) > i64::MAX - 1000);
+    assert!(Dec64::from(i128::MIN) > i128::MIN);
+    assert!(MAX > u128::MAX);
+    assert!(MIN < i128::MIN);
+    assert!(TINIEST > 0);
+    assert!(TINIEST < 1);
+    assert!(NEGATIVE_TINIEST > -1);
+    assert!(GOOGOL > u128::MAX);
+
+    // NaN is greater than all integers, like in the total order of Dec64.
+    assert!(NAN > i128::MAX);
+    assert!(NAN != 0);
+}
+
+#[test]
+fn float_comparisons() {
+    assert_eq!(TENTH, 0.1);
+    assert_eq!(0.5f32, HALF);
+    assert_eq!(TENTH, 0.1f32);
+    assert!(TENTH < 0.2);
+    assert!(-0.5 < ZERO);
+    assert!(MAX < f64::INFINITY);
+    assert!(MIN > f64::NEG_INFINITY);
+    assert!(!ZERO.eq(&f64::NAN));
+    assert_eq!(ZERO.partial_cmp(&f64::NAN), None);
+    assert!(NAN > 1.0);
+}
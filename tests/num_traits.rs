@@ -0,0 +1,115 @@
+#![cfg(feature = "num-traits")]
+#![allow(clippy::wildcard_imports)]
+
+use dec64::consts::*;
+use dec64::*;
+use num_traits::{
+    Bounded, CheckedAdd, CheckedDiv, CheckedNeg, CheckedRem, FromPrimitive, Num, NumCast, One, Pow,
+    SaturatingAdd, Signed, ToPrimitive, Zero,
+};
+
+/// Generic code like in statistics libraries.
+fn mean<T: Num + NumCast + Copy>(values: &[T]) -> T {
+    let sum = values.iter().fold(T::zero(), |sum, &value| sum + value);
+    sum / <T as NumCast>::from(values.len()).unwrap()
+}
+
+#[test]
+fn generic() {
+    assert_eq!(mean(&[ONE, TWO, Dec64::from_parts(6, 0)]), THREE);
+    assert_eq!(mean(&[TENTH, TENTH]), TENTH);
+}
+
+#[test]
+fn identities() {
+    assert_eq!(Dec64::zero(), ZERO);
+    assert!(Zero::is_zero(&ZIP));
+    assert!(!Zero::is_zero(&NAN));
+    assert_eq!(Dec64::one(), ONE);
+    assert_eq!(Dec64::min_value(), MIN);
+    assert_eq!(Dec64::max_value(), MAX);
+}
+
+#[test]
+fn from_str_radix() {
+    assert_eq!(
+        Dec64::from_str_radix("-1.5", 10),
+        Ok(Dec64::from_parts(-15, -1))
+    );
+    assert_eq!(
+        Dec64::from_str_radix("ff", 16),
+        Err(ParseDec64Error::Invalid)
+    );
+    assert_eq!(Dec64::from_str_radix("", 10), Err(ParseDec64Error::Empty));
+}
+
+#[test]
+fn signed() {
+    assert_eq!(Signed::abs(&NEGATIVE_TENTH), TENTH);
+    assert_eq!(NEGATIVE_TENTH.signum(), NEGATIVE_ONE);
+    assert_eq!(PI.signum(), ONE);
+    assert_eq!(ZIP.signum(), ZERO);
+    assert!(NAN.signum().is_nan());
+    assert_eq!(THREE.abs_sub(&ONE), TWO);
+    assert_eq!(ONE.abs_sub(&THREE), ZERO);
+    assert!(PI.is_positive());
+    assert!(!ZERO.is_positive() && !ZERO.is_negative());
+    assert!(!NAN.is_positive() && !NAN.is_negative());
+    assert!(NEGATIVE_TINIEST.is_negative());
+}
+
+#[test]
+fn from_primitive() {
+    assert_eq!(Dec64::from_i64(-3), Some(NEGATIVE_THREE));
+    assert_eq!(Dec64::from_u64(u64::MAX), Some(u64::MAX.into()));
+    assert_eq!(Dec64::from_f64(0.1), Some(TENTH));
+    assert_eq!(Dec64::from_f32(f32::NAN), None);
+    assert_eq!(Dec64::from_f64(f64::INFINITY), None);
+}
+
+#[test]
+fn to_primitive() {
+    assert_eq!(Dec64::from_parts(-27, -1).to_i64(), Some(-2));
+    assert_eq!(Dec64::from_parts(27, -1).to_u8(), Some(2));
+    assert_eq!(NEGATIVE_ONE.to_u32(), None);
+    assert_eq!(Dec64::from_parts(300, 0).to_u8(), None);
+    assert_eq!(NAN.to_i64(), None);
+    assert_eq!(MAX.to_i128(), None);
+    assert_eq!(GOOGOL.to_u128(), None);
+    assert_eq!(Dec64::from_parts(1, 38).to_u128(), Some(10u128.pow(38)));
+    assert_eq!(HALF.to_f64(), Some(0.5));
+    assert_eq!(TENTH.to_f32(), Some(0.1));
+}
+
+#[test]
+fn num_cast() {
+    assert_eq!(<Dec64 as NumCast>::from(7u8), Some(SEVEN));
+    assert_eq!(
+        <Dec64 as NumCast>::from(9007199254740993i64),
+        Some(Dec64::from_parts(9007199254740993, 0))
+    );
+    assert_eq!(<Dec64 as NumCast>::from(u128::MAX), Some(u128::MAX.into()));
+    assert_eq!(
+        <Dec64 as NumCast>::from(-0.25f64),
+        Some(Dec64::from_parts(-25, -2))
+    );
+    assert_eq!(<Dec64 as NumCast>::from(f64::NAN), None);
+    assert_eq!(<i32 as NumCast>::from(PI), Some(3));
+    assert_eq!(<f64 as NumCast>::from(PI), Some(core::f64::consts::PI));
+}
+
+#[test]
+fn checked() {
+    assert_eq!(CheckedAdd::checked_add(&MAX, &MAX), None);
+    assert_eq!(CheckedDiv::checked_div(&ONE, &ZERO), None);
+    assert_eq!(CheckedRem::checked_rem(&SEVEN, &TWO), Some(ONE));
+    assert_eq!(CheckedRem::checked_rem(&SEVEN, &ZERO), None);
+    assert_eq!(CheckedNeg::checked_neg(&MIN), None);
+    assert_eq!(SaturatingAdd::saturating_add(&MAX, &MAX), MAX);
+}
+
+#[test]
+fn pow() {
+    assert_eq!(Pow::pow(TWO, -2), Dec64::from_parts(25, -2));
+    assert_eq!(Pow::pow(FOUR, HALF), TWO);
+}
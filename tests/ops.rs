@@ -0,0 +1,57 @@
+#![allow(clippy::wildcard_imports)]
+
+use dec64::consts::*;
+use dec64::*;
+
+#[test]
+fn references() {
+    let values = [SIX, TWO, SEVEN];
+    let [a, b, seven] = &values;
+    assert_eq!(a + b, Dec64::from(8));
+    assert_eq!(a - values[1], FOUR);
+    assert_eq!(values[0] * b, Dec64::from(12));
+    assert_eq!(a / b, THREE);
+    assert_eq!(seven % b, ONE);
+    assert_eq!(-a, NEGATIVE_SIX);
+    assert!((a + NAN).is_nan());
+}
+
+#[test]
+fn assign() {
+    let mut total = ONE;
+    total += TWO;
+    assert_eq!(total, THREE);
+    total -= &ONE;
+    assert_eq!(total, TWO);
+    total *= TEN;
+    assert_eq!(total, Dec64::from(20));
+    total /= &EIGHT;
+    assert_eq!(total, Dec64::from_parts(25, -1));
+    total %= ONE;
+    assert_eq!(total, HALF);
+    total /= ZERO;
+    assert!(total.is_nan());
+}
+
+#[test]
+fn sum() {
+    let cents = [CENT; 100];
+    assert_eq!(cents.iter().sum::<Dec64>(), ONE);
+    assert_eq!(cents.into_iter().sum::<Dec64>(), ONE);
+    assert_eq!(core::iter::empty::<Dec64>().sum::<Dec64>(), ZERO);
+    assert!([ONE, NAN, TWO].iter().sum::<Dec64>().is_nan());
+    assert!([MAX, MAX].into_iter().sum::<Dec64>().is_nan());
+}
+
+#[test]
+fn product() {
+    let numbers = [ONE, TWO, THREE, FOUR, FIVE];
+    assert_eq!(numbers.iter().product::<Dec64>(), Dec64::from(120));
+    assert_eq!(numbers.into_iter().product::<Dec64>(), Dec64::from(120));
+    assert_eq!(core::iter::empty::<&Dec64>().product::<Dec64>(), ONE);
+    assert_eq!(
+        [TENTH; 3].iter().product::<Dec64>(),
+        Dec64::from_parts(1, -3)
+    );
+    assert!([ZERO, NAN].iter().product::<Dec64>().is_nan());
+}
@@ -0,0 +1,134 @@
+#![allow(clippy::wildcard_imports)]
+
+use dec64::consts::*;
+use dec64::*;
+
+macro_rules! assert_parse {
+    ($string:expr, $result:expr) => {
+        assert_eq!($string.parse::<Dec64>(), Ok($result), "{}", $string)
+    };
+}
+
+#[test]
+fn simple() {
+    assert_parse!("0", ZERO);
+    assert_parse!("1", ONE);
+    assert_parse!("-1", NEGATIVE_ONE);
+    assert_parse!("+1", ONE);
+    assert_parse!("10", TEN);
+    assert_parse!("0.5", HALF);
+    assert_parse!(".5", HALF);
+    assert_parse!("5.", FIVE);
+    assert_parse!("0.01", CENT);
+    assert_parse!("-0.2", NEGATIVE_FIFTH);
+    assert_parse!("000123.4500", Dec64::new(12345, -2));
+    assert_parse!("3.1415926535897932", PI);
+    assert_parse!("-3.1415926535897932", NEGATIVE_PI);
+}
+
+#[test]
+fn exponent() {
+    assert_parse!("1e2", Dec64::new(1, 2));
+    assert_parse!("1E2", Dec64::new(1, 2));
+    assert_parse!("1e+2", Dec64::new(1, 2));
+    assert_parse!("1.5e-2", Dec64::new(15, -3));
+    assert_parse!("1e-127", TINIEST);
+    assert_parse!("-1e-127", NEGATIVE_TINIEST);
+    assert_parse!("1e100", GOOGOL);
+    assert_parse!("3.6028797018963967e143", MAX);
+    assert_parse!("-3.6028797018963968e143", MIN);
+    assert_parse!("1e144", NAN);
+    assert_parse!("1e99999999999", NAN);
+    assert_parse!("1e-200", ZERO);
+    assert_parse!("1e-99999999999", ZERO);
+    assert_parse!("0e99999999999", ZERO);
+}
+
+#[test]
+fn nan() {
+    assert!("nan".parse::<Dec64>().unwrap().is_nan());
+    assert!("NaN".parse::<Dec64>().unwrap().is_nan());
+    assert!("NAN".parse::<Dec64>().unwrap().is_nan());
+}
+
+#[test]
+fn overlong() {
+    assert_parse!("36028797018963967", MAXINT);
+    assert_parse!("36028797018963968", Dec64::new(3602879701896397, 1));
+    assert_parse!(
+        "123456789012345678901234567890",
+        Dec64::new(12345678901234568, 13)
+    );
+    assert_parse!(
+        "0.5234567890123456549999",
+        Dec64::new(5234567890123457, -16)
+    );
+    assert_parse!(
+        "0.5234567890123456449999",
+        Dec64::new(5234567890123456, -16)
+    );
+    assert_parse!("-0.52345678901234565", Dec64::new(-5234567890123457, -16));
+    assert_parse!(
+        "0.000000000000000000000000000000000000000012345678901234567",
+        Dec64::new(12345678901234567, -57)
+    );
+}
+
+#[test]
+fn invalid() {
+    assert_eq!("".parse::<Dec64>(), Err(ParseDec64Error::Empty));
+    for string in [
+        "-", "+", ".", "e5", "1e", "1e+", "1.2.3", "--1", "1 ", " 1", "1_000", "0x10", "inf",
+        "-nan", "1e5.5", "nan1",
+    ] {
+        assert_eq!(
+            string.parse::<Dec64>(),
+            Err(ParseDec64Error::Invalid),
+            "{}",
+            string
+        );
+    }
+}
+
+#[test]
+fn roundtrip_display() {
+    let values = [
+        ZERO,
+        ONE,
+        NEGATIVE_ONE,
+        EPSILON,
+        MAX,
+        MIN,
+        TINIEST,
+        NEGATIVE_TINIEST,
+        MAXINT,
+        MININT,
+        ALMOST_ONE,
+        ALMOST_NEGATIVE_ONE,
+        PI,
+        FRAC_1_MAXINT,
+        Dec64::from_parts(3602879701896397, 1),
+        Dec64::from_parts(123456, -35),
+        Dec64::from_parts(123456, 30),
+    ];
+    for value in values {
+        assert_eq!(value.to_string().parse::<Dec64>(), Ok(value), "{:?}", value);
+    }
+}
+
+#[test]
+fn roundtrip_display_random() {
+    // xorshift64 so that the test is deterministic
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    for _ in 0..100_000 {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        let value = Dec64::from_raw(state as i64);
+        if value.is_nan() {
+            continue;
+        }
+        let string = value.to_string();
+        assert_eq!(string.parse::<Dec64>(), Ok(value), "{} {:?}", string, value);
+    }
+}
@@ -0,0 +1,100 @@
+#![allow(clippy::wildcard_imports)]
+
+use dec64::consts::*;
+use dec64::*;
+
+#[test]
+fn pow10() {
+    assert_eq!(Dec64::pow10(0), ONE);
+    assert_eq!(Dec64::pow10(-2), CENT);
+    assert_eq!(Dec64::pow10(100), GOOGOL);
+    assert_eq!(Dec64::pow10(-127), TINIEST);
+    assert_eq!(Dec64::pow10(127).coefficient(), 1);
+    assert_eq!(Dec64::pow10(128), Dec64::from_parts(10, 127));
+    assert_eq!(
+        Dec64::pow10(143),
+        Dec64::from_parts(10_000_000_000_000_000, 127)
+    );
+    assert!(Dec64::pow10(144).is_nan());
+    assert!(Dec64::pow10(i32::MAX).is_nan());
+    assert_eq!(Dec64::pow10(-128), ZERO);
+    assert_eq!(Dec64::pow10(i32::MIN), ZERO);
+}
+
+#[test]
+fn powi() {
+    let rate = Dec64::from_parts(105, -2);
+    assert_eq!(rate.powi(1), rate);
+    assert_eq!(rate.powi(2), Dec64::from_parts(11025, -4));
+    assert_eq!(rate.powi(10), Dec64::from_parts(16288946267774414, -16));
+    assert_eq!(rate.powi(30), Dec64::from_parts(4321942375150662, -15));
+    assert_eq!(
+        Dec64::from_parts(10025, -4).powi(360),
+        Dec64::from_parts(24568422114957275, -16)
+    );
+    assert_eq!(TWO.powi(10), Dec64::from(1024));
+    assert_eq!(TWO.powi(-3), Dec64::from_parts(125, -3));
+    assert_eq!(THREE.powi(-5), Dec64::from_parts(4115226337448560, -18));
+    assert_eq!(TEN.powi(127), Dec64::pow10(127));
+    assert_eq!(TENTH.powi(127), TINIEST);
+}
+
+#[test]
+fn powi_signs() {
+    assert_eq!(NEGATIVE_TWO.powi(3), -EIGHT);
+    assert_eq!(NEGATIVE_TWO.powi(-2), Dec64::from_parts(25, -2));
+    assert_eq!(NEGATIVE_ONE.powi(i32::MAX), NEGATIVE_ONE);
+    assert_eq!(NEGATIVE_ONE.powi(i32::MIN), ONE);
+    assert_eq!(ONE.powi(i32::MIN), ONE);
+}
+
+#[test]
+fn powi_special() {
+    assert_eq!(ZERO.powi(0), ONE);
+    assert_eq!(ZERO.powi(3), ZERO);
+    assert!(ZERO.powi(-1).is_nan());
+    assert!(NAN.powi(0).is_nan());
+    assert!(NAN.powi(2).is_nan());
+    assert!(TWO.powi(1000).is_nan());
+    assert!(MAX.powi(i32::MAX).is_nan());
+    assert!(HALF.powi(-1000).is_nan());
+    assert_eq!(TWO.powi(-1000), ZERO);
+    assert_eq!(HALF.powi(i32::MAX), ZERO);
+}
+
+#[test]
+fn powf() {
+    assert_eq!(FOUR.powf(HALF), TWO);
+    assert_eq!(TWO.powf(HALF), TWO.sqrt());
+    assert_eq!(TWO.powf(TEN), Dec64::from(1024));
+    assert_eq!(ONE.powf(PI), ONE);
+    assert_eq!(GOOGOL.powf(HALF), Dec64::pow10(50));
+    assert_eq!(
+        TEN.powf(Dec64::from_parts(25, -1)),
+        Dec64::from_parts(31622776601683793, -14)
+    );
+    assert_eq!(
+        Dec64::from_parts(15, -1).powf(Dec64::from_parts(-125, -2)),
+        Dec64::from_parts(6024013357398966, -16)
+    );
+    assert_eq!(
+        HALF.powf(Dec64::from_parts(1005, -1)),
+        Dec64::from_parts(5578088954947358, -46)
+    );
+}
+
+#[test]
+fn powf_special() {
+    assert_eq!(ZERO.powf(HALF), ZERO);
+    assert!(ZERO.powf(-HALF).is_nan());
+    assert!(NEGATIVE_ONE.powf(HALF).is_nan());
+    assert!(NAN.powf(ONE).is_nan());
+    assert!(ONE.powf(NAN).is_nan());
+    assert!(TWO.powf(GOOGOL).is_nan());
+    assert_eq!(TWO.powf(-GOOGOL), ZERO);
+    // Integral exponents beyond `i32` keep the sign of negative numbers if they are odd.
+    let odd = Dec64::from(10_000_000_001i64);
+    assert_eq!(NEGATIVE_ONE.powf(odd), NEGATIVE_ONE);
+    assert_eq!(NEGATIVE_ONE.powf(odd + ONE), ONE);
+    assert!(NEGATIVE_TWO.powf(odd).is_nan());
+}
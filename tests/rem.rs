@@ -0,0 +1,92 @@
+#![allow(clippy::wildcard_imports)]
+
+use dec64::consts::*;
+use dec64::*;
+
+fn dec(coefficient: i64, exponent: i8) -> Dec64 {
+    Dec64::from_parts(coefficient, exponent)
+}
+
+#[test]
+fn rem() {
+    assert_eq!(SEVEN % TWO, ONE);
+    assert_eq!(-SEVEN % TWO, NEGATIVE_ONE);
+    assert_eq!(SEVEN % NEGATIVE_TWO, ONE);
+    assert_eq!(-SEVEN % NEGATIVE_TWO, NEGATIVE_ONE);
+    assert_eq!(dec(55, -1) % TWO, dec(15, -1));
+    assert_eq!(TEN % dec(3, -1), dec(1, -1));
+    assert_eq!(PI % ONE, PI - THREE);
+    assert_eq!(SIX % THREE, ZERO);
+    assert_eq!(ZERO % THREE, ZERO);
+    assert_eq!(TINIEST % ONE, TINIEST);
+    assert_eq!(ONE % MAX, ONE);
+    // The quotient has far more digits than a DEC64, but the remainder is still exact.
+    assert_eq!(MAX % SEVEN, THREE);
+    assert_eq!(MAX % THREE, ONE);
+    assert_eq!(Dec64::new(1, 100) % TINIEST, ZERO);
+    assert_eq!(GOOGOL % dec(7, -3), dec(3, -3));
+    assert!((ONE % ZERO).is_nan());
+    assert!((NAN % ONE).is_nan());
+    assert!((ONE % NAN).is_nan());
+}
+
+#[test]
+fn div_rem() {
+    assert_eq!(TEN.div_rem(THREE), (THREE, ONE));
+    assert_eq!((-TEN).div_rem(THREE), (NEGATIVE_THREE, NEGATIVE_ONE));
+    assert_eq!(dec(10000, -2).div_rem(dec(3333, -2)), (THREE, CENT));
+    assert_eq!(dec(25, -1).div_rem(HALF), (FIVE, ZERO));
+    assert_eq!(ONE.div_rem(THREE), (ZERO, ONE));
+    assert_eq!(MAXINT.div_rem(ONE), (MAXINT, ZERO));
+    assert_eq!(MININT.div_rem(NEGATIVE_ONE), (-MININT, ZERO));
+    let (quotient, remainder) = MAX.div_rem(TINIEST);
+    assert!(quotient.is_nan());
+    assert_eq!(remainder, ZERO);
+    let (quotient, remainder) = GOOGOL.div_rem(THREE);
+    assert_eq!(quotient, GOOGOL / THREE);
+    assert_eq!(remainder, ONE);
+    let (quotient, remainder) = ONE.div_rem(ZERO);
+    assert!(quotient.is_nan() && remainder.is_nan());
+
+    for (a, b) in [
+        (PI, E),
+        (-PI, E),
+        (GOOGOL, PI),
+        (dec(123456, -3), dec(-7, -1)),
+    ] {
+        let (quotient, remainder) = a.div_rem(b);
+        assert!(quotient.is_integer());
+        assert!((a / b).floor() <= quotient && quotient <= (a / b).ceil());
+        assert_eq!(remainder, a % b);
+        if quotient.coefficient().abs() < MAX_COEFFICIENT / 100 {
+            assert_eq!(quotient * b + remainder, a);
+        }
+    }
+}
+
+#[test]
+fn euclid() {
+    for (a, b, quotient, remainder) in [
+        (SEVEN, TWO, THREE, ONE),
+        (-SEVEN, TWO, NEGATIVE_FOUR, ONE),
+        (SEVEN, NEGATIVE_TWO, NEGATIVE_THREE, ONE),
+        (-SEVEN, NEGATIVE_TWO, FOUR, ONE),
+        (-SIX, TWO, NEGATIVE_THREE, ZERO),
+        (dec(-55, -1), TWO, NEGATIVE_THREE, dec(5, -1)),
+        (-CENT, ONE, NEGATIVE_ONE, dec(99, -2)),
+    ] {
+        assert_eq!(a.div_euclid(b), quotient, "{a:?} div_euclid {b:?}");
+        assert_eq!(a.rem_euclid(b), remainder, "{a:?} rem_euclid {b:?}");
+        assert_eq!(quotient * b + remainder, a);
+    }
+    // Consistent with floor and ceil of the exact quotient.
+    assert_eq!((-PI).div_euclid(E), (-PI / E).floor());
+    assert_eq!((-PI).div_euclid(-E), (-PI / -E).ceil());
+    assert_eq!(PI.div_euclid(-E), (PI / -E).ceil());
+
+    // The remainder can have too many digits to be exact.
+    assert_eq!(NEGATIVE_TINIEST.rem_euclid(ONE), ONE);
+    assert_eq!(NEGATIVE_TINIEST.div_euclid(ONE), NEGATIVE_ONE);
+    assert!(ONE.div_euclid(ZERO).is_nan());
+    assert!(NAN.rem_euclid(ONE).is_nan());
+}
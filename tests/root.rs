@@ -0,0 +1,78 @@
+#![allow(clippy::wildcard_imports)]
+
+use dec64::consts::*;
+use dec64::*;
+
+#[test]
+fn sqrt() {
+    assert_eq!(Dec64::from_parts(225, -2).sqrt(), Dec64::from_parts(15, -1));
+    assert_eq!(Dec64::from_parts(225, -2).sqrt().coefficient(), 15);
+    assert_eq!(Dec64::from(144).sqrt(), Dec64::from(12));
+    assert_eq!(Dec64::from_parts(1, -2).sqrt(), TENTH);
+    assert_eq!(
+        Dec64::from_parts(1, 1).sqrt(),
+        Dec64::from_parts(31622776601683793, -16)
+    );
+    assert_eq!(TWO.sqrt(), Dec64::from_parts(14142135623730950, -16));
+    assert_eq!(THREE.sqrt(), Dec64::from_parts(17320508075688773, -16));
+    assert_eq!(MAX.sqrt(), Dec64::from_parts(6002399271871538, 56));
+    assert_eq!(TINIEST.sqrt(), Dec64::from_parts(31622776601683793, -80));
+    assert_eq!(ZERO.sqrt(), ZERO);
+    assert_eq!(ZIP.sqrt(), ZERO);
+    assert!(NEGATIVE_TINIEST.sqrt().is_nan());
+    assert!(NAN.sqrt().is_nan());
+}
+
+#[test]
+fn rsqrt() {
+    assert_eq!(Dec64::from_parts(4, -2).rsqrt(), FIVE);
+    assert_eq!(Dec64::from(100).rsqrt(), TENTH);
+    assert_eq!(TWO.rsqrt(), Dec64::from_parts(7071067811865475, -16));
+    assert_eq!(THREE.rsqrt(), Dec64::from_parts(5773502691896258, -16));
+    assert_eq!(TINIEST.rsqrt(), Dec64::from_parts(31622776601683793, 47));
+    assert!(ZERO.rsqrt().is_nan());
+    assert!(NEGATIVE_ONE.rsqrt().is_nan());
+    assert!(NAN.rsqrt().is_nan());
+}
+
+#[test]
+fn cbrt() {
+    assert_eq!(Dec64::from_parts(-27, -3).cbrt(), Dec64::from_parts(-3, -1));
+    assert_eq!(Dec64::from(1000).cbrt(), TEN);
+    assert_eq!(
+        Dec64::from_parts(3375, -6).cbrt(),
+        Dec64::from_parts(15, -2)
+    );
+    assert_eq!(TWO.cbrt(), Dec64::from_parts(12599210498948732, -16));
+    assert_eq!(-TEN.cbrt(), Dec64::from_parts(-21544346900318837, -16));
+    assert_eq!(MAX.cbrt(), Dec64::from_parts(7115682916846552, 32));
+    assert_eq!(TINIEST.cbrt(), Dec64::from_parts(4641588833612779, -58));
+    assert_eq!(ZERO.cbrt(), ZERO);
+    assert!(NAN.cbrt().is_nan());
+}
+
+#[test]
+fn hypot() {
+    assert_eq!(THREE.hypot(-FOUR), FIVE);
+    assert_eq!(ONE.hypot(ONE), TWO.sqrt());
+    assert_eq!(ZERO.hypot(NEGATIVE_TENTH), TENTH);
+    assert_eq!(NEGATIVE_TENTH.hypot(ZERO), TENTH);
+    // The squares are far out of range.
+    let large = Dec64::from_parts(MAX_COEFFICIENT, 126);
+    assert_eq!(large.hypot(large), Dec64::from_parts(5095241338020618, 127));
+    assert!(MAX.hypot(MAX).is_nan());
+    let small = Dec64::from_parts(1, -120);
+    assert_eq!(small.hypot(small), Dec64::from_parts(14142136, -127));
+    assert_eq!(
+        Dec64::from_parts(3, -127).hypot(Dec64::from_parts(4, -127)),
+        Dec64::from_parts(5, -127)
+    );
+    // The smaller number only affects rounding.
+    assert_eq!(ONE.hypot(TINIEST), ONE);
+    assert_eq!(
+        Dec64::from_parts(10000000000000000, -16).hypot(Dec64::from_parts(14142135623730951, -24)),
+        Dec64::from_parts(10000000000000001, -16)
+    );
+    assert!(NAN.hypot(ONE).is_nan());
+    assert!(ONE.hypot(NAN).is_nan());
+}
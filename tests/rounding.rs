@@ -0,0 +1,151 @@
+#![allow(clippy::wildcard_imports)]
+
+use dec64::consts::*;
+use dec64::*;
+
+const MODES: [RoundingMode; 7] = [
+    RoundingMode::HalfEven,
+    RoundingMode::HalfUp,
+    RoundingMode::HalfDown,
+    RoundingMode::TowardZero,
+    RoundingMode::AwayFromZero,
+    RoundingMode::Floor,
+    RoundingMode::Ceiling,
+];
+
+/// Rounds numbers with one decimal place to integers in all modes.
+fn round_all(coefficient: i64) -> [Dec64; 7] {
+    MODES.map(|mode| Dec64::from_parts(coefficient, -1).round_to_places_with(0, mode))
+}
+
+fn ints(values: [i64; 7]) -> [Dec64; 7] {
+    values.map(Dec64::from)
+}
+
+#[test]
+fn round_to_places() {
+    assert_eq!(round_all(25), ints([2, 3, 2, 2, 3, 2, 3]));
+    assert_eq!(round_all(35), ints([4, 4, 3, 3, 4, 3, 4]));
+    assert_eq!(round_all(-25), ints([-2, -3, -2, -2, -3, -3, -2]));
+    assert_eq!(round_all(21), ints([2, 2, 2, 2, 3, 2, 3]));
+    assert_eq!(round_all(-27), ints([-3, -3, -3, -2, -3, -3, -2]));
+    assert_eq!(round_all(20), ints([2; 7]));
+    assert_eq!(round_all(4), ints([0, 0, 0, 0, 1, 0, 1]));
+    assert_eq!(round_all(-5), ints([0, -1, 0, 0, -1, -1, 0]));
+
+    let cents = Dec64::from_parts(12345, -4);
+    assert_eq!(
+        cents.round_to_places_with(-2, RoundingMode::HalfEven),
+        Dec64::from_parts(123, -2)
+    );
+    assert_eq!(
+        cents.round_to_places_with(-2, RoundingMode::Ceiling),
+        Dec64::from_parts(124, -2)
+    );
+    assert_eq!(
+        TINIEST.round_to_places_with(100, RoundingMode::AwayFromZero),
+        Dec64::new(1, 100)
+    );
+    assert_eq!(
+        NEGATIVE_TINIEST.round_to_places_with(100, RoundingMode::Floor),
+        Dec64::new(-1, 100)
+    );
+    assert_eq!(
+        TINIEST.round_to_places_with(100, RoundingMode::HalfUp),
+        ZERO
+    );
+    assert_eq!(MAX.round_to_places_with(0, RoundingMode::Floor), MAX);
+    assert!(NAN.round_to_places_with(0, RoundingMode::Floor).is_nan());
+
+    let low = Dec64::from_parts(314, -2);
+    let high = Dec64::from_parts(315, -2);
+    let pi = MODES.map(|mode| PI.round_to_places_with(-2, mode));
+    assert_eq!(pi, [low, low, low, low, high, low, high]);
+    let pi = MODES.map(|mode| (-PI).round_to_places_with(-2, mode));
+    assert_eq!(pi, [-low, -low, -low, -low, -high, -high, -low]);
+}
+
+#[test]
+fn new() {
+    // 2^55 + 7 has one digit too many for a DEC64 coefficient.
+    let tie = 36028797018963975;
+    let values = MODES.map(|mode| Dec64::new_rounded(tie, 0, mode));
+    let expected = [
+        3602879701896398,
+        3602879701896398,
+        3602879701896397,
+        3602879701896397,
+        3602879701896398,
+        3602879701896397,
+        3602879701896398,
+    ]
+    .map(|coefficient| Dec64::from_parts(coefficient, 1));
+    assert_eq!(values, expected);
+    assert_eq!(
+        Dec64::new(tie, 0),
+        Dec64::new_rounded(tie, 0, RoundingMode::HalfUp)
+    );
+    assert_eq!(
+        Dec64::new_rounded(-tie, 0, RoundingMode::Floor),
+        Dec64::from_parts(-3602879701896398, 1)
+    );
+
+    assert_eq!(Dec64::new_rounded(1, -128, RoundingMode::HalfUp), ZERO);
+    assert_eq!(Dec64::new_rounded(1, -128, RoundingMode::Ceiling), TINIEST);
+    assert_eq!(
+        Dec64::new_rounded(-1, -128, RoundingMode::Floor),
+        NEGATIVE_TINIEST
+    );
+    assert_eq!(Dec64::new_rounded(5, -128, RoundingMode::HalfEven), ZERO);
+    assert_eq!(
+        Dec64::new_rounded(15, -128, RoundingMode::HalfEven),
+        Dec64::from_parts(2, -127)
+    );
+}
+
+#[test]
+fn mul_div() {
+    let two_thirds = MODES.map(|mode| TWO.div_rounded(THREE, mode));
+    let low = Dec64::from_parts(6666666666666666, -16);
+    let high = Dec64::from_parts(6666666666666667, -16);
+    assert_eq!(two_thirds, [high, high, high, low, high, low, high]);
+    assert_eq!(TWO / THREE, high);
+    assert_eq!(
+        NEGATIVE_ONE.div_rounded(THREE, RoundingMode::Ceiling),
+        Dec64::from_parts(-33333333333333333, -17)
+    );
+    assert_eq!(
+        NEGATIVE_ONE.div_rounded(THREE, RoundingMode::Floor),
+        Dec64::from_parts(-33333333333333334, -17)
+    );
+    assert!(ONE.div_rounded(ZERO, RoundingMode::Floor).is_nan());
+
+    // The product 36028797018963975 is a tie between two DEC64 numbers.
+    let a = Dec64::from_parts(7205759403792795, 0);
+    let product = MODES.map(|mode| a.mul_rounded(FIVE, mode));
+    let low = Dec64::from_parts(3602879701896397, 1);
+    let high = Dec64::from_parts(3602879701896398, 1);
+    assert_eq!(product, [high, high, low, low, high, low, high]);
+    let product = MODES.map(|mode| (-a).mul_rounded(FIVE, mode));
+    assert_eq!(product, [-high, -high, -low, -low, -high, -high, -low]);
+    assert_eq!(a * FIVE, high);
+
+    assert_eq!(TINIEST.mul_rounded(TENTH, RoundingMode::Ceiling), TINIEST);
+    assert_eq!(TINIEST.mul_rounded(TENTH, RoundingMode::HalfUp), ZERO);
+    assert!(MAX.mul_rounded(TWO, RoundingMode::TowardZero).is_nan());
+}
+
+#[test]
+fn add_sub() {
+    let sums = MODES.map(|mode| ONE.add_rounded(Dec64::from_parts(5, -17), mode));
+    let low = Dec64::from_parts(10000000000000000, -16);
+    let high = Dec64::from_parts(10000000000000001, -16);
+    assert_eq!(sums, [low, high, low, low, high, low, high]);
+
+    let differences = MODES.map(|mode| ONE.sub_rounded(TINIEST, mode));
+    let low = Dec64::from_parts(9999999999999999, -16);
+    assert_eq!(differences, [ONE, ONE, ONE, low, ONE, low, ONE]);
+    assert_eq!(ONE - TINIEST, ONE);
+    assert_eq!(ONE.add_rounded(ONE, RoundingMode::Floor), TWO);
+    assert!(ONE.sub_rounded(NAN, RoundingMode::Floor).is_nan());
+}
@@ -0,0 +1,49 @@
+#![allow(clippy::wildcard_imports)]
+
+use dec64::consts::*;
+
+#[test]
+fn add() {
+    assert_eq!(ONE.saturating_add(TWO), THREE);
+    assert_eq!(MAX.saturating_add(MAX), MAX);
+    assert_eq!(MAX.saturating_add(ONE), MAX);
+    assert_eq!(MIN.saturating_add(MIN), MIN);
+    assert_eq!(MIN.saturating_add(-MAX), MIN);
+    assert_eq!(MAX.saturating_add(MIN), MAX + MIN);
+    assert!(NAN.saturating_add(ONE).is_nan());
+    assert!(MAX.saturating_add(NAN).is_nan());
+}
+
+#[test]
+fn sub() {
+    assert_eq!(THREE.saturating_sub(ONE), TWO);
+    assert_eq!(MAX.saturating_sub(MIN), MAX);
+    assert_eq!(MIN.saturating_sub(MAX), MIN);
+    assert_eq!(ZERO.saturating_sub(MIN), MAX);
+    assert_eq!(MIN.saturating_sub(MIN), ZERO);
+    assert!(NAN.saturating_sub(MIN).is_nan());
+}
+
+#[test]
+fn mul() {
+    assert_eq!(TWO.saturating_mul(THREE), SIX);
+    assert_eq!(MAX.saturating_mul(TWO), MAX);
+    assert_eq!(MAX.saturating_mul(NEGATIVE_TWO), MIN);
+    assert_eq!(MIN.saturating_mul(MIN), MAX);
+    assert_eq!(MIN.saturating_mul(TWO), MIN);
+    assert_eq!(TINIEST.saturating_mul(TINIEST), ZERO);
+    assert_eq!(NEGATIVE_TINIEST.saturating_mul(TENTH), ZERO);
+    assert!(NAN.saturating_mul(TWO).is_nan());
+}
+
+#[test]
+fn div() {
+    assert_eq!(SIX.saturating_div(TWO), THREE);
+    assert_eq!(MAX.saturating_div(TENTH), MAX);
+    assert_eq!(MAX.saturating_div(NEGATIVE_TENTH), MIN);
+    assert_eq!(MIN.saturating_div(TINIEST), MIN);
+    assert_eq!(MIN.saturating_div(NEGATIVE_TINIEST), MAX);
+    assert_eq!(TINIEST.saturating_div(TEN), ZERO);
+    assert!(ONE.saturating_div(ZERO).is_nan());
+    assert!(NAN.saturating_div(ONE).is_nan());
+}
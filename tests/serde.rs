@@ -0,0 +1,107 @@
+#![cfg(feature = "serde")]
+#![allow(clippy::wildcard_imports)]
+
+use dec64::Dec64;
+use dec64::consts::*;
+use serde::de::IntoDeserializer;
+use serde::de::value::Error;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Amounts {
+    default: Dec64,
+    #[serde(with = "dec64::serde::as_str")]
+    string: Dec64,
+    #[serde(with = "dec64::serde::as_f64")]
+    float: Dec64,
+    #[serde(with = "dec64::serde::as_raw_i64")]
+    raw: Dec64,
+}
+
+#[test]
+fn serialize() {
+    let amounts = Amounts {
+        default: Dec64::from_parts(-12345, -2),
+        string: TENTH,
+        float: HALF,
+        raw: ONE,
+    };
+    let json = serde_json::to_string(&amounts).unwrap();
+    assert_eq!(
+        json,
+        r#"{"default":"-123.45","string":"0.1","float":0.5,"raw":256}"#
+    );
+    assert_eq!(serde_json::from_str::<Amounts>(&json).unwrap(), amounts);
+    assert_eq!(serde_json::to_string(&NAN).unwrap(), r#""nan""#);
+}
+
+#[test]
+fn deserialize_default() {
+    assert_eq!(
+        serde_json::from_str::<Dec64>(r#""1.50""#).unwrap(),
+        Dec64::from_parts(150, -2)
+    );
+    assert_eq!(serde_json::from_str::<Dec64>(r#""nan""#).unwrap(), NAN);
+    assert!(serde_json::from_str::<Dec64>(r#""1.5.0""#).is_err());
+    assert!(serde_json::from_str::<Dec64>("[1]").is_err());
+    assert!(serde_json::from_str::<Dec64>(r#"{"a": "1"}"#).is_err());
+
+    // Primitives from formats without arbitrary-precision numbers.
+    let from_i64 = Dec64::deserialize(IntoDeserializer::<Error>::into_deserializer(-7i64));
+    assert_eq!(from_i64, Ok(-SEVEN));
+    let from_u128 = Dec64::deserialize(IntoDeserializer::<Error>::into_deserializer(u128::MAX));
+    assert_eq!(from_u128, Ok(Dec64::from(u128::MAX)));
+    let from_f64 = Dec64::deserialize(IntoDeserializer::<Error>::into_deserializer(0.1f64));
+    assert_eq!(from_f64, Ok(TENTH));
+}
+
+#[test]
+fn arbitrary_precision() {
+    // These would not survive a conversion to f64.
+    assert_eq!(
+        serde_json::from_str::<Dec64>("0.30000000000000001").unwrap(),
+        Dec64::from_parts(30000000000000001, -17)
+    );
+    assert_eq!(
+        serde_json::from_str::<Dec64>("36028797018963967").unwrap(),
+        MAXINT
+    );
+    assert_eq!(
+        serde_json::from_str::<Dec64>("-1.25e-100").unwrap(),
+        Dec64::from_parts(-125, -102)
+    );
+    assert_eq!(
+        serde_json::from_str::<Dec64>("123456789012345678901234567890").unwrap(),
+        Dec64::from_parts(12345678901234568, 13)
+    );
+}
+
+#[test]
+fn modes() {
+    assert!(
+        serde_json::from_str::<Amounts>(r#"{"default":1,"string":1,"float":1,"raw":256}"#).is_err()
+    );
+    let amounts =
+        serde_json::from_str::<Amounts>(r#"{"default":1,"string":"1","float":1,"raw":-128}"#)
+            .unwrap();
+    assert_eq!(amounts.float, ONE);
+    assert_eq!(amounts.raw, Dec64::from_raw(-128));
+    assert!(amounts.raw.is_nan());
+}
+
+#[test]
+fn not_human_readable() {
+    // bincode is not self-describing, so the default representation must not ask for any type.
+    let amounts = Amounts {
+        default: Dec64::from_parts(-12345, -2),
+        string: TENTH,
+        float: HALF,
+        raw: NAN,
+    };
+    let bytes = bincode::serialize(&amounts).unwrap();
+    assert_eq!(bincode::deserialize::<Amounts>(&bytes).unwrap(), amounts);
+    let bytes = bincode::serialize(&PI).unwrap();
+    assert_eq!(bincode::deserialize::<Dec64>(&bytes).unwrap(), PI);
+    let bytes = bincode::serialize("1.5.0").unwrap();
+    assert!(bincode::deserialize::<Dec64>(&bytes).is_err());
+}
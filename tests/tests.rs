@@ -10,7 +10,7 @@ use dec64::*;
 fn roundtrip_usize() {
     let dec = Dec64::from(255_usize);
 
-    let num: usize = dec.into();
+    let num: usize = dec.try_into().unwrap();
 
     assert_eq!(num, 255_usize);
 }
@@ -19,7 +19,7 @@ fn roundtrip_usize() {
 fn roundtrip_u8() {
     let dec = Dec64::from(255_u8);
 
-    let num: u8 = dec.into();
+    let num: u8 = dec.try_into().unwrap();
 
     assert_eq!(num, 255_u8);
 }
@@ -28,7 +28,7 @@ fn roundtrip_u8() {
 fn roundtrip_u16() {
     let dec = Dec64::from(255_u16);
 
-    let num: u16 = dec.into();
+    let num: u16 = dec.try_into().unwrap();
 
     assert_eq!(num, 255_u16);
 }
@@ -37,7 +37,7 @@ fn roundtrip_u16() {
 fn roundtrip_u32() {
     let dec = Dec64::from(255_u32);
 
-    let num: u32 = dec.into();
+    let num: u32 = dec.try_into().unwrap();
 
     assert_eq!(num, 255_u32);
 }
@@ -46,7 +46,7 @@ fn roundtrip_u32() {
 fn roundtrip_u64() {
     let dec = Dec64::from(255_u64);
 
-    let num: u64 = dec.into();
+    let num: u64 = dec.try_into().unwrap();
 
     assert_eq!(num, 255_u64);
 }
@@ -55,7 +55,7 @@ fn roundtrip_u64() {
 fn roundtrip_isize() {
     let dec = Dec64::from(-128_isize);
 
-    let num: isize = dec.into();
+    let num: isize = dec.try_into().unwrap();
 
     assert_eq!(num, -128_isize);
 }
@@ -64,7 +64,7 @@ fn roundtrip_isize() {
 fn roundtrip_i8() {
     let dec = Dec64::from(-128_i8);
 
-    let num: i8 = dec.into();
+    let num: i8 = dec.try_into().unwrap();
 
     assert_eq!(num, -128_i8);
 }
@@ -73,7 +73,7 @@ fn roundtrip_i8() {
 fn roundtrip_i16() {
     let dec = Dec64::from(-128_i16);
 
-    let num: i16 = dec.into();
+    let num: i16 = dec.try_into().unwrap();
 
     assert_eq!(num, -128_i16);
 }
@@ -82,7 +82,7 @@ fn roundtrip_i16() {
 fn roundtrip_i32() {
     let dec = Dec64::from(-128_i32);
 
-    let num: i32 = dec.into();
+    let num: i32 = dec.try_into().unwrap();
 
     assert_eq!(num, -128_i32);
 }
@@ -91,7 +91,7 @@ fn roundtrip_i32() {
 fn roundtrip_i64() {
     let dec = Dec64::from(-128_i64);
 
-    let num: i64 = dec.into();
+    let num: i64 = dec.try_into().unwrap();
 
     assert_eq!(num, -128_i64);
 }
@@ -272,6 +272,20 @@ fn write_huge() {
     assert_eq!(string, "1.23456e35");
 }
 
+#[test]
+fn write_huge_three_digit_exponent() {
+    let dec = Dec64::from_parts(31442300607620681, 85);
+    let string = dec.to_string();
+    assert_eq!(string, "3.1442300607620681e101");
+}
+
+#[test]
+fn write_integer_with_negative_exponent() {
+    let dec = Dec64::from_parts(1200, -1);
+    let string = dec.to_string();
+    assert_eq!(string, "120");
+}
+
 /// Same tests as C implementation
 #[test]
 fn write_c() {
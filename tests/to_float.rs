@@ -0,0 +1,72 @@
+#![allow(clippy::wildcard_imports)]
+
+use dec64::consts::*;
+use dec64::*;
+
+#[test]
+fn correctly_rounded() {
+    // The coefficient has more bits than the mantissa, so converting it before scaling rounds twice.
+    assert_eq!(
+        f64::from(Dec64::from_parts(9007199254740993, 1)),
+        90071992547409940.0
+    );
+    assert_eq!(
+        f64::from(Dec64::from_parts(36028797018963967, -17)),
+        0.36028797018963967
+    );
+    assert_eq!(
+        f64::from(Dec64::from_parts(17976931348623157, 100)),
+        1.7976931348623157e116
+    );
+    assert_eq!(
+        f64::from(Dec64::from_parts(-4940656458412, -127)),
+        -4.940656458412e-115
+    );
+    assert_eq!(f64::from(TENTH), 0.1);
+    assert_eq!(f64::from(PI), core::f64::consts::PI);
+    assert_eq!(f64::from(MAX), 3.6028797018963967e143);
+    assert_eq!(f64::from(TINIEST), 1e-127);
+}
+
+#[test]
+fn correctly_rounded_f32() {
+    assert_eq!(f32::from(TENTH), 0.1);
+    assert_eq!(f32::from(Dec64::from_parts(16777217, 0)), 16777216.0);
+    assert_eq!(f32::from(Dec64::from_parts(16777219, 0)), 16777220.0);
+    assert_eq!(f32::from(Dec64::from_parts(3402823466385288, 23)), f32::MAX);
+    assert_eq!(f32::from(Dec64::from_parts(1401298464324817, -60)), 1e-45);
+    assert_eq!(f32::from(Dec64::from_parts(7, -46)), 0.0);
+    assert_eq!(f32::from(MAX), f32::INFINITY);
+    assert_eq!(f32::from(MIN), f32::NEG_INFINITY);
+    assert_eq!(f32::from(TINIEST), 0.0);
+}
+
+#[test]
+fn nan() {
+    assert!(f64::from(NAN).is_nan());
+    assert!(f32::from(NONNORMAL_NAN).is_nan());
+    assert_eq!(NAN.to_f64_exact(), None);
+}
+
+#[test]
+fn exact() {
+    assert_eq!(ZERO.to_f64_exact(), Some(0.0));
+    assert_eq!(NEGATIVE_ONE.to_f64_exact(), Some(-1.0));
+    assert_eq!(
+        Dec64::from_parts(29296875, -10).to_f64_exact(),
+        Some(0.0029296875)
+    );
+    assert_eq!(
+        Dec64::from_parts(9007199254740992, 0).to_f64_exact(),
+        Some(9007199254740992.0)
+    );
+    assert_eq!(Dec64::from_parts(9007199254740993, 0).to_f64_exact(), None);
+    assert_eq!(Dec64::from_parts(3, 22).to_f64_exact(), Some(3e22));
+    assert_eq!(Dec64::from_parts(5, 22).to_f64_exact(), None);
+    assert_eq!(Dec64::from_parts(1, 22).to_f64_exact(), Some(1e22));
+    assert_eq!(Dec64::from_parts(1, 23).to_f64_exact(), None);
+    assert_eq!(Dec64::from_parts(1, 23 * 2).to_f64_exact(), None);
+    assert_eq!(MAX.to_f64_exact(), None);
+    assert_eq!(TENTH.to_f64_exact(), None);
+    assert_eq!(TINIEST.to_f64_exact(), None);
+}
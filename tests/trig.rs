@@ -0,0 +1,92 @@
+#![allow(clippy::wildcard_imports)]
+
+use dec64::consts::*;
+use dec64::*;
+
+#[test]
+fn sin() {
+    assert_eq!(ZERO.sin(), ZERO);
+    assert_eq!(TINIEST.sin(), TINIEST);
+    assert_eq!(ONE.sin(), Dec64::from_parts(8414709848078965, -16));
+    assert_eq!(
+        NEGATIVE_ONE.sin(),
+        Dec64::from_parts(-8414709848078965, -16)
+    );
+    assert_eq!(FRAC_PI_2.sin(), ONE);
+    assert_eq!(PI.sin(), Dec64::from_parts(3846264338327950, -32));
+    assert_eq!(
+        Dec64::from(100).sin(),
+        Dec64::from_parts(-5063656411097588, -16)
+    );
+    assert!(NAN.sin().is_nan());
+}
+
+#[test]
+fn cos() {
+    assert_eq!(ZERO.cos(), ONE);
+    assert_eq!(TINIEST.cos(), ONE);
+    assert_eq!(ONE.cos(), Dec64::from_parts(5403023058681397, -16));
+    assert_eq!(NEGATIVE_ONE.cos(), ONE.cos());
+    assert_eq!(FRAC_PI_2.cos(), Dec64::from_parts(19231321691639751, -33));
+    assert_eq!(PI.cos(), NEGATIVE_ONE);
+    assert!(NAN.cos().is_nan());
+}
+
+#[test]
+fn tan() {
+    assert_eq!(ZERO.tan(), ZERO);
+    assert_eq!(TINIEST.tan(), TINIEST);
+    assert_eq!(ONE.tan(), Dec64::from_parts(15574077246549022, -16));
+    assert_eq!(NEGATIVE_ONE.tan(), -ONE.tan());
+    assert_eq!(FRAC_PI_2.tan(), Dec64::from_parts(5199850618872027, 1));
+    assert_eq!(PI.tan(), Dec64::from_parts(-3846264338327950, -32));
+    assert!(NAN.tan().is_nan());
+}
+
+#[test]
+fn large_arguments() {
+    // Close to a multiple of π.
+    assert_eq!(
+        Dec64::from(355).sin(),
+        Dec64::from_parts(-30144353359488449, -21)
+    );
+    assert_eq!(
+        Dec64::from_parts(1, 16).sin(),
+        Dec64::from_parts(7796880066069788, -16)
+    );
+    assert_eq!(
+        Dec64::from_parts(1, 22).sin(),
+        Dec64::from_parts(-8522008497671888, -16)
+    );
+    assert_eq!(
+        Dec64::from_parts(1, 22).cos(),
+        Dec64::from_parts(5232147853951389, -16)
+    );
+    assert_eq!(MAX.sin(), Dec64::from_parts(8822361100173514, -17));
+    assert_eq!(MAX.cos(), Dec64::from_parts(9961006949409354, -16));
+    assert_eq!((-MAX).sin(), -MAX.sin());
+    assert_eq!((-MAX).cos(), MAX.cos());
+}
+
+#[test]
+fn sin_cos() {
+    assert_eq!(ZERO.sin_cos(), (ZERO, ONE));
+    assert_eq!(ONE.sin_cos(), (ONE.sin(), ONE.cos()));
+    assert_eq!(MAX.sin_cos(), (MAX.sin(), MAX.cos()));
+    let (sin, cos) = NAN.sin_cos();
+    assert!(sin.is_nan() && cos.is_nan());
+}
+
+#[test]
+fn degrees() {
+    assert_eq!(ZERO.to_degrees(), ZERO);
+    assert_eq!(PI.to_degrees(), Dec64::from(180));
+    assert_eq!(ONE.to_degrees(), Dec64::from_parts(5729577951308232, -14));
+    assert_eq!(Dec64::from(180).to_radians(), PI);
+    assert_eq!(Dec64::from(90).to_radians(), FRAC_PI_2);
+    assert_eq!(ONE.to_radians(), Dec64::from_parts(17453292519943296, -18));
+    assert!(MAX.to_degrees().is_nan());
+    assert_eq!(TINIEST.to_radians(), ZERO);
+    assert!(NAN.to_degrees().is_nan());
+    assert!(NAN.to_radians().is_nan());
+}
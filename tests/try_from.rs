@@ -0,0 +1,84 @@
+#![allow(clippy::wildcard_imports)]
+
+use dec64::consts::*;
+use dec64::*;
+
+#[test]
+fn integers() {
+    assert_eq!(i32::try_from(Dec64::from(-128)), Ok(-128));
+    assert_eq!(u8::try_from(Dec64::from(255)), Ok(255));
+    assert_eq!(i64::try_from(MAXINT), Ok(MAX_COEFFICIENT));
+    assert_eq!(i64::try_from(MININT), Ok(MIN_COEFFICIENT));
+    assert_eq!(i64::try_from(ZERO), Ok(0));
+    assert_eq!(u64::try_from(Dec64::from_parts(0, -100)), Ok(0));
+    assert_eq!(i32::try_from(Dec64::from_parts(12, 3)), Ok(12000));
+    assert_eq!(i32::try_from(Dec64::from_parts(1200, -2)), Ok(12));
+    assert_eq!(u128::try_from(Dec64::from_parts(1, 38)), Ok(10u128.pow(38)));
+    assert_eq!(
+        i128::try_from(Dec64::from_parts(-17014118346046923, 22)),
+        Ok(-170141183460469230000000000000000000000)
+    );
+    assert_eq!(
+        i64::try_from(Dec64::from_parts(-9223372036854775, 3)),
+        Ok(-9223372036854775000)
+    );
+}
+
+#[test]
+fn errors() {
+    use TryFromDec64Error::*;
+
+    assert_eq!(i64::try_from(NAN), Err(Nan));
+    assert_eq!(u8::try_from(NONNORMAL_NAN), Err(Nan));
+    assert_eq!(i64::try_from(HALF), Err(Fractional));
+    assert_eq!(i64::try_from(TINIEST), Err(Fractional));
+    assert_eq!(u32::try_from(Dec64::from_parts(1201, -2)), Err(Fractional));
+    // The quantity that overflowed i32 when truncating silently.
+    assert_eq!(
+        i32::try_from(Dec64::from_parts(3_000_000, 3)),
+        Err(OutOfRange)
+    );
+    assert_eq!(u8::try_from(Dec64::from(256)), Err(OutOfRange));
+    assert_eq!(i8::try_from(Dec64::from(-129)), Err(OutOfRange));
+    assert_eq!(u64::try_from(NEGATIVE_ONE), Err(OutOfRange));
+    assert_eq!(i64::try_from(Dec64::from_parts(1, 19)), Err(OutOfRange));
+    assert_eq!(u128::try_from(MAX), Err(OutOfRange));
+    assert_eq!(i128::try_from(MIN), Err(OutOfRange));
+    assert_eq!(
+        i128::try_from(Dec64::from_parts(17014118346046924, 22)),
+        Err(OutOfRange)
+    );
+}
+
+#[test]
+fn rounded() {
+    let amount = Dec64::from_parts(25, -1);
+    assert_eq!(amount.to_i64_rounded(RoundingMode::HalfEven), Ok(2));
+    assert_eq!(amount.to_i64_rounded(RoundingMode::HalfUp), Ok(3));
+    assert_eq!((-amount).to_i64_rounded(RoundingMode::Floor), Ok(-3));
+    assert_eq!((-amount).to_i128_rounded(RoundingMode::TowardZero), Ok(-2));
+    assert_eq!(PI.to_u64_rounded(RoundingMode::Ceiling), Ok(4));
+    assert_eq!(TINIEST.to_u128_rounded(RoundingMode::AwayFromZero), Ok(1));
+    assert_eq!(
+        NEGATIVE_ONE.to_u64_rounded(RoundingMode::HalfUp),
+        Err(TryFromDec64Error::OutOfRange)
+    );
+    assert_eq!(NEGATIVE_TENTH.to_u64_rounded(RoundingMode::HalfUp), Ok(0));
+    assert_eq!(
+        NAN.to_i64_rounded(RoundingMode::HalfUp),
+        Err(TryFromDec64Error::Nan)
+    );
+    assert_eq!(
+        MAX.to_i64_rounded(RoundingMode::HalfUp),
+        Err(TryFromDec64Error::OutOfRange)
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn display() {
+    assert_eq!(
+        TryFromDec64Error::Fractional.to_string(),
+        "DEC64 has a fractional part"
+    );
+}
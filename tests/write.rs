@@ -0,0 +1,114 @@
+#![allow(clippy::wildcard_imports)]
+
+use dec64::consts::*;
+use dec64::*;
+
+#[test]
+fn precision() {
+    assert_eq!(format!("{:.2}", PI), "3.14");
+    assert_eq!(format!("{:.0}", PI), "3");
+    assert_eq!(format!("{:.4}", NEGATIVE_PI), "-3.1416");
+    assert_eq!(format!("{:.2}", ONE), "1.00");
+    assert_eq!(format!("{:.2}", ZERO), "0.00");
+    assert_eq!(format!("{:.3}", CENT), "0.010");
+    assert_eq!(format!("{:.1}", CENT), "0.0");
+    assert_eq!(format!("{:.2}", Dec64::new(-1, -3)), "0.00");
+    assert_eq!(format!("{:.1}", Dec64::new(1200, -1)), "120.0");
+    assert_eq!(format!("{:.20}", EPSILON), "0.00000000000000010000");
+    assert_eq!(
+        format!("{:.3}", Dec64::new(123456, 30)),
+        format!("123456{:0>30}.000", "")
+    );
+}
+
+#[test]
+fn precision_rounds_half_away() {
+    assert_eq!(format!("{:.2}", Dec64::new(1005, -3)), "1.01");
+    assert_eq!(format!("{:.2}", Dec64::new(-1005, -3)), "-1.01");
+    assert_eq!(format!("{:.2}", Dec64::new(10049, -4)), "1.00");
+    assert_eq!(format!("{:.1}", Dec64::new(995, -3)), "1.0");
+    assert_eq!(format!("{:.0}", HALF), "1");
+    assert_eq!(format!("{:.0}", -HALF), "-1");
+    assert_eq!(format!("{:.2}", ALMOST_ONE), "1.00");
+}
+
+#[test]
+fn sign() {
+    assert_eq!(format!("{:+}", ONE), "+1");
+    assert_eq!(format!("{:+}", NEGATIVE_ONE), "-1");
+    assert_eq!(format!("{:+}", ZERO), "+0");
+    assert_eq!(format!("{:+.2}", HALF), "+0.50");
+    assert_eq!(format!("{:+}", NAN), "nan");
+}
+
+#[test]
+fn width() {
+    assert_eq!(format!("{:8}", PI.round_to_places(-2)), "    3.14");
+    assert_eq!(format!("{:<8}", HALF), "0.5     ");
+    assert_eq!(format!("{:^8}", HALF), "  0.5   ");
+    assert_eq!(format!("{:>8}", NEGATIVE_ONE), "      -1");
+    assert_eq!(format!("{:*>8.2}", NEGATIVE_ONE), "***-1.00");
+    assert_eq!(format!("{:_<+8}", ONE), "+1______");
+    assert_eq!(format!("{:2}", TEN), "10");
+    assert_eq!(format!("{:1}", NEGATIVE_TEN), "-10");
+    assert_eq!(format!("{:6}", NAN), "   nan");
+    assert_eq!(format!("{:<6}", NAN), "nan   ");
+    assert_eq!(format!("{:12}", TINIEST), "      1e-127");
+}
+
+#[test]
+fn zero_pad() {
+    assert_eq!(format!("{:08.2}", PI), "00003.14");
+    assert_eq!(format!("{:08.2}", NEGATIVE_PI), "-0003.14");
+    assert_eq!(format!("{:+08}", ONE), "+0000001");
+    assert_eq!(format!("{:<08}", ONE), "00000001");
+    assert_eq!(format!("{:06}", NAN), "   nan");
+}
+
+#[test]
+fn lower_exp() {
+    assert_eq!(format!("{:e}", ZERO), "0e0");
+    assert_eq!(format!("{:e}", ONE), "1e0");
+    assert_eq!(format!("{:e}", TEN), "1e1");
+    assert_eq!(format!("{:e}", Dec64::new(1200, -1)), "1.2e2");
+    assert_eq!(format!("{:e}", NEGATIVE_PI), "-3.1415926535897932e0");
+    assert_eq!(format!("{:e}", CENT), "1e-2");
+    assert_eq!(format!("{:e}", MAX), "3.6028797018963967e143");
+    assert_eq!(format!("{:e}", TINIEST), "1e-127");
+    assert_eq!(format!("{:e}", NAN), "nan");
+}
+
+#[test]
+fn upper_exp() {
+    assert_eq!(format!("{:E}", Dec64::new(123456, 30)), "1.23456E35");
+    assert_eq!(format!("{:E}", Dec64::new(-5, -3)), "-5E-3");
+}
+
+#[test]
+fn exp_precision() {
+    assert_eq!(format!("{:.2e}", PI), "3.14e0");
+    assert_eq!(format!("{:.0e}", PI), "3e0");
+    assert_eq!(format!("{:.3e}", ONE), "1.000e0");
+    assert_eq!(format!("{:.2e}", ZERO), "0.00e0");
+    assert_eq!(format!("{:.1e}", Dec64::new(995, 0)), "1.0e3");
+    assert_eq!(format!("{:.1e}", Dec64::new(-125, -3)), "-1.3e-1");
+    assert_eq!(format!("{:.0e}", MAX), "4e143");
+    assert_eq!(format!("{:+12.3E}", Dec64::new(12345, 0)), "    +1.235E4");
+    assert_eq!(format!("{:012.3e}", Dec64::new(-12345, 0)), "-00001.235e4");
+}
+
+#[test]
+fn engineering() {
+    assert_eq!(format!("{:#e}", ONE), "1e0");
+    assert_eq!(format!("{:#e}", TEN), "10e0");
+    assert_eq!(format!("{:#e}", Dec64::new(1, 3)), "1e3");
+    assert_eq!(format!("{:#e}", Dec64::new(12345, 0)), "12.345e3");
+    assert_eq!(format!("{:#e}", Dec64::new(123456, 0)), "123.456e3");
+    assert_eq!(format!("{:#e}", CENT), "10e-3");
+    assert_eq!(format!("{:#e}", Dec64::new(-47, -8)), "-470e-9");
+    assert_eq!(format!("{:#e}", TINIEST), "100e-129");
+    assert_eq!(format!("{:#E}", Dec64::new(123456, 30)), "123.456E33");
+    assert_eq!(format!("{:#.2e}", Dec64::new(999999, 0)), "1.00e6");
+    assert_eq!(format!("{:#.1e}", Dec64::new(12345, 0)), "12.3e3");
+    assert_eq!(format!("{:#.0e}", ZERO), "0e0");
+}